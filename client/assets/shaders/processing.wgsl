@@ -1,21 +1,65 @@
 @group(0) @binding(0) var current: texture_storage_2d<rgba8unorm, read>;
 @group(0) @binding(1) var previous: texture_storage_2d<rgba8unorm, read>;
 @group(0) @binding(2) var output: texture_storage_2d<rgba8unorm, read_write>;
+@group(0) @binding(3) var<uniform> settings: MotionDetectionSettings;
+// Pixels the diff pass found changed this frame.
+@group(0) @binding(4) var<storage, read_write> changed_pixels: atomic<u32>;
 
 
 @group(1) @binding(0) var difference: texture_storage_2d<rgba8unorm, read_write>;
 @group(1) @binding(1) var<uniform> u: RaymarchUniforms;
-@group(1) @binding(2) var voxel_grid: texture_storage_3d<r32float, read_write>;
+#ifdef SPARSE_HITS
+@group(1) @binding(2) var<storage, read_write> hits: HitBuffer;
+#else
+// Per-voxel sums in units of 1 / FIXED_POINT_SCALE, indexed by voxel_id.
+@group(1) @binding(2) var<storage, read_write> voxel_grid: VoxelSums;
+#endif
 
 struct RaymarchUniforms {
-    camera_pos: vec3<f32>,
-    camera_rotation: mat3x3<f32>,
+    // Camera space to world space. The camera looks down its -Z axis with +X right and +Y up in
+    // the image; world space is right-handed with +Y up.
+    camera_to_world: mat4x4<f32>,
     screen_size: vec2<f32>,
-    grid_center: vec3<f32>,
-    voxel_n: i32,
+    grid_origin: vec3<f32>,
+    grid_rotation: mat3x3<f32>,
+    grid_dims: vec3<i32>,
     voxel_size: f32,
     focal_length: f32,
-    changed_threshold: f32,
+}
+
+struct MotionDetectionSettings {
+    grayscale_weights: vec3<f32>,
+    threshold: f32,
+    min_changed_pixels: u32,
+    max_ray_steps: u32,
+}
+
+// Coordinates rather than a voxel_id, which would wrap for grids of more than 2^32 voxels.
+struct VoxelHit {
+    x: u32,
+    y: u32,
+    z: u32,
+    value: f32,
+}
+
+// Written by the CPU ahead of the marks, naming the frame they come from. Both fields are
+// little-endian 64-bit integers: the frame id and the capture time in microseconds.
+struct FrameHeader {
+    frame_id: vec2<u32>,
+    captured_at: vec2<u32>,
+}
+
+struct VoxelSums {
+    frame: FrameHeader,
+    sums: array<atomic<u32>>,
+}
+
+// Hits appended by the raymarch pass. `count` keeps counting past the end of `items`, so the CPU
+// can tell how many were dropped.
+struct HitBuffer {
+    frame: FrameHeader,
+    count: atomic<u32>,
+    items: array<VoxelHit>,
 }
 
 
@@ -26,16 +70,16 @@ fn diff(@builtin(global_invocation_id) invocation_id: vec3<u32>, @builtin(num_wo
     let current_value = textureLoad(current, location);
     let previous_value = textureLoad(previous, location);
     let d = abs(to_grayscale(previous_value) - to_grayscale(current_value));
-    let threshold = 0.05;
     var color = vec4<f32>(0.0,0.0,0.0,1.0);
-    if d >= threshold {
+    if d >= settings.threshold {
        color = vec4<f32>(d,d,d,1.0); 
+       atomicAdd(&changed_pixels, 1u);
     }
     textureStore(output, location, color);
 }
 
 fn to_grayscale(color: vec4<f32>) -> f32 {
-    return dot(color.rgb, vec3<f32>(0.299,0.587,0.114));
+    return dot(color.rgb, settings.grayscale_weights);
 }
 
 fn safe_div(a: f32, b:f32) -> f32 {
@@ -46,19 +90,43 @@ fn safe_div(a: f32, b:f32) -> f32 {
 }
 
 
+// Linear index of the voxel, x varying fastest. A dense buffer small enough to bind keeps it within
+// a u32.
+fn voxel_id(voxel: vec3<i32>, grid_dims: vec3<i32>) -> u32 {
+    let dims = vec3<u32>(grid_dims);
+    let v = vec3<u32>(voxel);
+    return v.x + v.y * dims.x + v.z * dims.x * dims.y;
+}
+
+fn mark_voxel(voxel: vec3<i32>, grid_dims: vec3<i32>, diff: f32) {
+#ifdef SPARSE_HITS
+    let index = atomicAdd(&hits.count, 1u);
+    if (index < arrayLength(&hits.items)) {
+        let v = vec3<u32>(voxel);
+        hits.items[index] = VoxelHit(v.x, v.y, v.z, diff);
+    }
+#else
+    // Integer adds commute, so the sum is the same whichever order rays reach the voxel in.
+    let fixed = u32(round(diff * f32(#{FIXED_POINT_SCALE})));
+    atomicAdd(&voxel_grid.sums[voxel_id(voxel, grid_dims)], fixed);
+#endif
+}
+
+fn outside_grid(voxel: vec3<i32>, grid_dims: vec3<i32>) -> bool {
+    return any(voxel < vec3<i32>(0)) || any(voxel >= grid_dims);
+}
+
+// camera_pos and dir are in the grid's local frame, where the grid spans [0, grid_dims * voxel_size].
 fn cast_ray_into_grid(
     camera_pos: vec3<f32>,
     dir: vec3<f32>,
-    voxel_n: i32,
+    grid_dims: vec3<i32>,
     voxel_size: f32,
-    grid_center: vec3<f32>,
     diff: f32
 ) {
-    var step_count = 0;
-    let half_size = 0.5 * (f32(u.voxel_n) * u.voxel_size);
-    let half_grid = vec3<f32>(half_size);
-    let grid_min = grid_center - half_grid;
-    let grid_max = grid_center + half_grid;
+    var step_count = 0u;
+    let grid_min = vec3<f32>(0.0);
+    let grid_max = vec3<f32>(grid_dims) * voxel_size;
 
     var t_min = 0.0;
     var t_max = 1e30;
@@ -95,7 +163,7 @@ fn cast_ray_into_grid(
     var iy = i32(f.y);
     var iz = i32(f.z);
 
-    if (ix < 0 || ix >= voxel_n || iy < 0 || iy >= voxel_n || iz < 0 || iz >= voxel_n) {
+    if (outside_grid(vec3<i32>(ix, iy, iz), grid_dims)) {
         return;
     }
 
@@ -121,11 +189,9 @@ fn cast_ray_into_grid(
 
     var t_current = t_min;
 
-    while(t_current <=t_max && step_count < 64) {
+    while(t_current <=t_max && step_count < settings.max_ray_steps) {
         
-        let voxel_coord = vec3<i32>(ix,iy,iz);
-        let current_val = textureLoad(voxel_grid, voxel_coord).r;
-        textureStore(voxel_grid,voxel_coord, vec4<f32>(current_val+diff, 0.0,0.0,1.0));
+        mark_voxel(vec3<i32>(ix,iy,iz), grid_dims, diff);
 
         if (t_max_x < t_max_y && t_max_x < t_max_z) {
             ix += step_x;
@@ -142,7 +208,7 @@ fn cast_ray_into_grid(
 
         step_count ++;
 
-        if (ix < 0 || ix >= voxel_n || iy < 0 || iy >= voxel_n || iz < 0 || iz >= voxel_n) {
+        if (outside_grid(vec3<i32>(ix, iy, iz), grid_dims)) {
             break;
         }
     }
@@ -156,8 +222,13 @@ fn raymarch(@builtin(global_invocation_id) invocation_id: vec3<u32>) {
     if (pixel_coord.x >= screen_size.x || pixel_coord.y >= screen_size.y) {
         return;
     }
+    // Too few changed pixels is more likely sensor noise than motion.
+    if (atomicLoad(&changed_pixels) < settings.min_changed_pixels) {
+        return;
+    }
+    // The diff pass already zeroed pixels below the threshold.
     let diff = textureLoad(difference, pixel_coord).r;
-    if (diff <= u.changed_threshold) {
+    if (diff <= 0.0) {
         return;
     }
     let uc = f32(pixel_coord.x);
@@ -169,10 +240,12 @@ fn raymarch(@builtin(global_invocation_id) invocation_id: vec3<u32>) {
     let y = -(v - 0.5 * height);
     let z = -u.focal_length;
 
-    var ray_cam = vec3<f32>(x,y,z);
-    ray_cam = normalize(ray_cam);
+    let ray_cam = normalize(vec3<f32>(x,y,z));
 
-    let ray_world = normalize(u.camera_rotation * ray_cam);
-    cast_ray_into_grid(u.camera_pos, ray_cam, u.voxel_n, u.voxel_size, u.grid_center, diff);
+    let camera_pos = (u.camera_to_world * vec4<f32>(0.0, 0.0, 0.0, 1.0)).xyz;
+    let ray_world = normalize((u.camera_to_world * vec4<f32>(ray_cam, 0.0)).xyz);
+    let grid_pos = u.grid_rotation * (camera_pos - u.grid_origin);
+    let grid_dir = u.grid_rotation * ray_world;
+    cast_ray_into_grid(grid_pos, grid_dir, u.grid_dims, u.voxel_size, diff);
 
 }
@@ -17,8 +17,9 @@ impl Plugin for AppPlugin {
         app.add_plugins((
             DefaultPlugins,
             plugins::camera::VoxelCameraPlugin,
-            plugins::processing::ImageProcessingPlugin,
+            plugins::processing::ImageProcessingPlugin::from_env(),
             plugins::connection::ConnectionPlugin,
+            plugins::clock::ClockSyncPlugin,
         ));
     }
 }
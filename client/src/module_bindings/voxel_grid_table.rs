@@ -84,6 +84,7 @@ impl<'ctx> __sdk::Table for VoxelGridTableHandle<'ctx> {
 pub(super) fn register_table(client_cache: &mut __sdk::ClientCache<super::RemoteModule>) {
     let _table = client_cache.get_or_make_table::<VoxelGrid>("voxel_grid");
     _table.add_unique_constraint::<u32>("id", |row| &row.id);
+    _table.add_unique_constraint::<String>("name", |row| &row.name);
 }
 pub struct VoxelGridUpdateCallbackId(__sdk::CallbackId);
 
@@ -142,3 +143,33 @@ impl<'ctx> VoxelGridIdUnique<'ctx> {
         self.imp.find(col_val)
     }
 }
+
+/// Access to the `name` unique index on the table `voxel_grid`,
+/// which allows point queries on the field of the same name
+/// via the [`VoxelGridNameUnique::find`] method.
+///
+/// Users are encouraged not to explicitly reference this type,
+/// but to directly chain method calls,
+/// like `ctx.db.voxel_grid().name().find(...)`.
+pub struct VoxelGridNameUnique<'ctx> {
+    imp: __sdk::UniqueConstraintHandle<VoxelGrid, String>,
+    phantom: std::marker::PhantomData<&'ctx super::RemoteTables>,
+}
+
+impl<'ctx> VoxelGridTableHandle<'ctx> {
+    /// Get a handle on the `name` unique index on the table `voxel_grid`.
+    pub fn name(&self) -> VoxelGridNameUnique<'ctx> {
+        VoxelGridNameUnique {
+            imp: self.imp.get_unique_constraint::<String>("name"),
+            phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'ctx> VoxelGridNameUnique<'ctx> {
+    /// Find the subscribed row whose `name` column value is equal to `col_val`,
+    /// if such a row is present in the client cache.
+    pub fn find(&self, col_val: &String) -> Option<VoxelGrid> {
+        self.imp.find(col_val)
+    }
+}
@@ -6,6 +6,7 @@ use bevy::{
     },
 };
 use nokhwa::{pixel_format::RgbAFormat, utils::RequestedFormat, *};
+use spacetimedb_sdk::Timestamp;
 pub struct VoxelCameraPlugin;
 
 impl Plugin for VoxelCameraPlugin {
@@ -28,11 +29,6 @@ impl Plugin for VoxelCameraPlugin {
                 roll: 0.0,
                 fov: 90.0,
             })
-            .insert_resource(VoxelInfo {
-                n: 10,
-                voxel_size: 1.0,
-                grid_center: vec3(5.0, 5.0, 5.0),
-            })
             .insert_resource(Time::<Fixed>::from_hz(fps));
     }
 }
@@ -70,6 +66,8 @@ pub fn setup(
         prev: prev.clone(),
         size: image_size,
         new_frame: true,
+        frame_id: 0,
+        captured_at: Timestamp::now(),
     });
     commands.insert_resource(DisplayTexture {
         handle: display.clone(),
@@ -92,7 +90,9 @@ pub fn camera_to_texture(
     mut cam_text: ResMut<CameraTextures>,
 ) {
     cam.open_stream();
-    let frame = cam.frame().unwrap().decode_image::<RgbAFormat>().unwrap();
+    let buffer = cam.frame().unwrap();
+    let captured_at = Timestamp::now();
+    let frame = buffer.decode_image::<RgbAFormat>().unwrap();
 
     let current_handle = cam_text.prev.clone();
 
@@ -101,5 +101,7 @@ pub fn camera_to_texture(
         image.data = Some(frame.to_vec());
         cam_text.new_frame = true;
         cam_text.current = current_handle;
+        cam_text.frame_id += 1;
+        cam_text.captured_at = captured_at;
     }
 }
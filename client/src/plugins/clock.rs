@@ -0,0 +1,88 @@
+use crate::module_bindings::*;
+use crate::prelude::*;
+use bevy::time::common_conditions::on_timer;
+use bevy_spacetimedb::*;
+use spacetimedb_sdk::{Status, Timestamp};
+use std::sync::{
+    Mutex,
+    mpsc::{Receiver, channel},
+};
+use std::time::Duration;
+
+/// Pings the server to keep [`ClockSync`] current and reports the estimate to the camera
+/// registry.
+pub struct ClockSyncPlugin;
+const PING_INTERVAL: Duration = Duration::from_secs(2);
+const REPORT_INTERVAL: Duration = Duration::from_secs(10);
+
+impl Plugin for ClockSyncPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ClockSync>().add_systems(
+            Update,
+            (
+                on_connected,
+                send_ping.run_if(on_timer(PING_INTERVAL)),
+                receive_pings,
+                report_clock_offset.run_if(on_timer(REPORT_INTERVAL)),
+            ),
+        );
+    }
+}
+
+/// Completed pings, passed over from the connection's thread.
+#[derive(Resource)]
+struct PingReceiver(Mutex<Receiver<ClockSample>>);
+
+fn on_connected(
+    mut commands: Commands,
+    mut events: ReadStdbConnectedEvent,
+    stdb: Res<StdbConnection<DbConnection>>,
+) {
+    for _ in events.read() {
+        let (sender, receiver) = channel();
+        stdb.reducers().on_ping(move |ctx, client_time| {
+            if !matches!(ctx.event.status, Status::Committed) {
+                return;
+            }
+            let _ = sender.send(ClockSample {
+                sent_micros: client_time.to_micros_since_unix_epoch(),
+                received_micros: Timestamp::now().to_micros_since_unix_epoch(),
+                server_micros: ctx.event.timestamp.to_micros_since_unix_epoch(),
+            });
+        });
+        commands.insert_resource(PingReceiver(Mutex::new(receiver)));
+    }
+}
+
+fn send_ping(stdb: Option<Res<StdbConnection<DbConnection>>>) {
+    let Some(stdb) = stdb else {
+        return;
+    };
+    if let Err(err) = stdb.reducers().ping(Timestamp::now()) {
+        error!("failed to ping server: {err}");
+    }
+}
+
+fn receive_pings(receiver: Option<Res<PingReceiver>>, mut clock: ResMut<ClockSync>) {
+    let Some(receiver) = receiver else {
+        return;
+    };
+    let receiver = receiver.0.lock().unwrap();
+    for sample in receiver.try_iter() {
+        clock.add_sample(sample);
+    }
+}
+
+fn report_clock_offset(clock: Res<ClockSync>, stdb: Option<Res<StdbConnection<DbConnection>>>) {
+    let (Some(stdb), Some(estimate)) = (stdb, clock.estimate()) else {
+        return;
+    };
+    let offset_micros = estimate.offset_at(Timestamp::now().to_micros_since_unix_epoch());
+    if let Err(err) = stdb.reducers().report_clock_offset(
+        offset_micros.round() as i64,
+        estimate.drift_ppm,
+        estimate.round_trip_micros,
+    ) {
+        error!("failed to report clock offset: {err}");
+    }
+}
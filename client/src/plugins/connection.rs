@@ -1,31 +1,212 @@
 use crate::module_bindings::*;
 use crate::prelude::*;
 use bevy_spacetimedb::*;
+use spacetimedb_sdk::{Identity, Status, credentials};
 pub struct ConnectionPlugin;
 const HOST: &str = "http://localhost:3000";
 const DB_NAME: &str = "voxel";
+/// Environment variable naming this camera in the server's camera registry.
+const CAMERA_NAME_VAR: &str = "VOXEL_CAMERA_NAME";
+/// Environment variable naming the server grid this camera covers.
+const GRID_NAME_VAR: &str = "VOXEL_GRID";
 
 impl Plugin for ConnectionPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(
-            StdbPlugin::default()
-                .with_uri(HOST)
-                .with_module_name(DB_NAME)
-                .with_run_fn(DbConnection::run_threaded),
-        )
-        .add_systems(Update, send_voxel_update);
+        // Reconnecting with the saved token keeps the identity an admin granted the camera role.
+        let token = credentials().load().unwrap_or_else(|err| {
+            warn!("connecting as a new identity, failed to load the saved token: {err}");
+            None
+        });
+        let mut stdb = StdbPlugin::default()
+            .with_uri(HOST)
+            .with_module_name(DB_NAME)
+            .with_run_fn(DbConnection::run_threaded)
+            .add_table(RemoteTables::voxel_grid)
+            .add_table(RemoteTables::authorized_identity);
+        if let Some(token) = token {
+            stdb = stdb.with_token(token);
+        }
+        app.add_plugins(stdb).add_systems(
+            Update,
+            (
+                on_connected,
+                (register_when_authorized, sync_voxel_info).chain(),
+                send_camera_pose.run_if(resource_changed::<FrameInfo>),
+                send_voxel_update,
+            ),
+        );
+    }
+}
+
+/// The identity this client is connected as.
+#[derive(Resource)]
+struct LocalIdentity(Identity);
+
+fn camera_name() -> String {
+    std::env::var(CAMERA_NAME_VAR).unwrap_or_else(|_| "camera".to_string())
+}
+
+/// Where the token of the camera named by [`CAMERA_NAME_VAR`] is saved between runs.
+fn credentials() -> credentials::File {
+    credentials::File::new(format!("{DB_NAME}_{}", camera_name()))
+}
+
+fn on_connected(
+    mut commands: Commands,
+    mut events: ReadStdbConnectedEvent,
+    stdb: Res<StdbConnection<DbConnection>>,
+) {
+    for event in events.read() {
+        if let Err(err) = credentials().save(&event.access_token) {
+            error!("failed to save the connection token: {err}");
+        }
+        info!(
+            "connected as {}; hits are accepted once an admin grants it the Camera role",
+            event.identity
+        );
+        commands.insert_resource(LocalIdentity(event.identity));
+        stdb.subscription_builder().subscribe([
+            "SELECT * FROM voxel_grid",
+            "SELECT * FROM authorized_identity",
+        ]);
+        if let Err(err) = stdb
+            .reducers()
+            .set_client_version(env!("CARGO_PKG_VERSION").to_string())
+        {
+            error!("failed to report client version: {err}");
+        }
+
+        // Sending only reports errors of the connection; the server's refusals arrive here.
+        stdb.reducers()
+            .on_register_camera(|ctx, _, _, _, _, _, _, _| {
+                if let Status::Failed(err) = &ctx.event.status {
+                    error!("failed to register camera: {err}");
+                }
+            });
+        stdb.reducers().on_cover_grid(|ctx, grid_id| {
+            if let Status::Failed(err) = &ctx.event.status {
+                error!("failed to cover grid {grid_id}: {err}");
+            }
+        });
+        stdb.reducers()
+            .on_update_voxels(|ctx, _, frame_id, _, _, _| {
+                if let Status::Failed(err) = &ctx.event.status {
+                    error!("server refused frame {frame_id}: {err}");
+                }
+            });
+    }
+}
+
+/// Registers the camera, and covers its grid if known, whenever this client's identity is granted
+/// a role. The subscription reports an existing grant as soon as it applies, so this also
+/// registers on every connect, and retries what was refused before an admin got to it.
+fn register_when_authorized(
+    mut inserted: ReadInsertEvent<AuthorizedIdentity>,
+    mut updated: ReadUpdateEvent<AuthorizedIdentity>,
+    identity: Option<Res<LocalIdentity>>,
+    stdb: Option<Res<StdbConnection<DbConnection>>>,
+    camera_textures: Res<CameraTextures>,
+    frame_info: Res<FrameInfo>,
+    voxel_info: Option<Res<VoxelInfo>>,
+) {
+    let (Some(identity), Some(stdb)) = (identity, stdb) else {
+        return;
+    };
+    let granted = inserted
+        .read()
+        .map(|event| &event.row)
+        .chain(updated.read().map(|event| &event.new))
+        .any(|row| row.identity == identity.0 && matches!(row.role, Role::Camera | Role::Admin));
+    if !granted {
+        return;
+    }
+
+    let size = camera_textures.size.as_uvec2();
+    if let Err(err) = stdb.reducers().register_camera(
+        camera_name(),
+        size.x,
+        size.y,
+        frame_info.fov,
+        frame_info.focal_length(size.x as f32),
+        frame_info.camera_position.into(),
+        frame_info.rotation().into(),
+    ) {
+        error!("failed to register camera: {err}");
+    }
+    if let Some(voxel_info) = voxel_info
+        && let Err(err) = stdb.reducers().cover_grid(voxel_info.grid_id)
+    {
+        error!("failed to cover grid {}: {err}", voxel_info.grid_id);
+    }
+}
+
+/// Keeps the camera registry's pose in line with [`FrameInfo`] after registration.
+fn send_camera_pose(frame_info: Res<FrameInfo>, stdb: Option<Res<StdbConnection<DbConnection>>>) {
+    let Some(stdb) = stdb else {
+        return;
+    };
+    if frame_info.is_added() {
+        return;
+    }
+    if let Err(err) = stdb.reducers().update_camera_pose(
+        frame_info.camera_position.into(),
+        frame_info.rotation().into(),
+    ) {
+        error!("failed to update camera pose: {err}");
+    }
+}
+
+/// Keeps [`VoxelInfo`] in line with the server's row for the grid named by [`GRID_NAME_VAR`], and
+/// declares that this camera covers it once it shows up.
+fn sync_voxel_info(
+    mut commands: Commands,
+    mut inserted: ReadInsertEvent<VoxelGrid>,
+    mut updated: ReadUpdateEvent<VoxelGrid>,
+    voxel_info: Option<Res<VoxelInfo>>,
+    stdb: Option<Res<StdbConnection<DbConnection>>>,
+) {
+    let name = std::env::var(GRID_NAME_VAR).unwrap_or_else(|_| "default".to_string());
+    let mut grid_id = voxel_info.map(|info| info.grid_id);
+    let rows = inserted
+        .read()
+        .map(|event| &event.row)
+        .chain(updated.read().map(|event| &event.new));
+    for grid in rows {
+        if grid.name != name {
+            continue;
+        }
+        info!(
+            "using grid {} {:?}: {}x{}x{} voxels of size {}",
+            grid.id, grid.name, grid.nx, grid.ny, grid.nz, grid.voxel_size
+        );
+        if grid_id != Some(grid.id)
+            && let Some(stdb) = &stdb
+            && let Err(err) = stdb.reducers().cover_grid(grid.id)
+        {
+            error!("failed to cover grid {}: {err}", grid.id);
+        }
+        grid_id = Some(grid.id);
+        commands.insert_resource(VoxelInfo::from(grid));
     }
 }
 
 pub fn send_voxel_update(
-    mut events: EventReader<VoxelHitEvent>,
+    mut events: EventReader<VoxelFrameEvent>,
     stdb: Option<Res<StdbConnection<DbConnection>>>,
+    clock: Res<ClockSync>,
 ) {
     if let Some(stdb) = stdb {
         for event in events.read() {
-            info!("yay :D");
-            stdb.reducers()
-                .update_voxel(event.voxel.clone(), event.value);
+            if let Err(err) = stdb.reducers().update_voxels(
+                event.grid_id,
+                event.frame_id,
+                clock.to_server_time(event.captured_at),
+                event.hits.clone(),
+                // The raymarcher doesn't report the voxels its rays pass through yet.
+                Vec::new(),
+            ) {
+                error!("failed to send frame {}: {err}", event.frame_id);
+            }
         }
     }
 }
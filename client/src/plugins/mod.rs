@@ -1,4 +1,5 @@
 pub(super) mod camera;
+pub(super) mod clock;
 pub(super) mod connection;
 pub(super) mod processing;
 pub(super) mod test;
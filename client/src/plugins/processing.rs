@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::collections::HashMap;
 
 use bevy::{
     asset::RenderAssetUsages,
@@ -10,22 +11,62 @@ use bevy::{
         render_graph::{self, Node, RenderGraph, RenderLabel},
         render_resource::{
             BindGroupLayoutEntries, PipelineCache, ShaderStages,
-            binding_types::{texture_storage_2d, texture_storage_3d, uniform_buffer},
+            binding_types::{
+                storage_buffer, storage_buffer_sized, texture_storage_2d, uniform_buffer,
+            },
             *,
         },
         renderer::{RenderDevice, RenderQueue},
+        storage::{GpuShaderStorageBuffer, ShaderStorageBuffer},
         texture::GpuImage,
     },
+    shader::ShaderDefVal,
 };
 use bevy_spacetimedb::*;
+use spacetimedb_sdk::Timestamp;
 
-use crate::module_bindings::{update_voxel_reducer::update_voxel, voxel_type::Voxel};
+use crate::module_bindings::{Voxel, VoxelHit};
 use crate::prelude::*;
 
-pub struct ImageProcessingPlugin;
+#[derive(Default)]
+pub struct ImageProcessingPlugin {
+    pub marking: MarkingMode,
+}
+
+impl ImageProcessingPlugin {
+    /// Marks sparsely if [`MARKING_VAR`] is `sparse`, densely otherwise.
+    pub fn from_env() -> Self {
+        let marking = match std::env::var(MARKING_VAR).as_deref() {
+            Ok("sparse") => MarkingMode::Sparse {
+                capacity: DEFAULT_HIT_CAPACITY,
+            },
+            _ => MarkingMode::Dense,
+        };
+        Self { marking }
+    }
+}
 
 const SHADER_ASSET_PATH: &str = "shaders/processing.wgsl";
 const WORKGROUP_SIZE: u32 = 8;
+/// Environment variable overriding the default [`MotionDetectionSettings::threshold`].
+const THRESHOLD_VAR: &str = "VOXEL_MOTION_THRESHOLD";
+/// Environment variable selecting the [`MarkingMode`], `dense` or `sparse`.
+const MARKING_VAR: &str = "VOXEL_MARKING";
+const DEFAULT_HIT_CAPACITY: u32 = 1 << 20;
+/// Bytes of the frame header at the start of the [`VoxelGridBuffer`] and the [`HitBuffer`]: the
+/// `u64` id and the `i64` capture time in microseconds of the frame the marks behind it came from.
+const FRAME_HEADER_SIZE: u64 = 16;
+/// Bytes of the hit count following the frame header of the [`HitBuffer`].
+const HIT_COUNT_SIZE: u64 = size_of::<u32>() as u64;
+/// Bytes of one hit in the [`HitBuffer`]: the voxel's `u32` coordinates and an `f32` value.
+/// Coordinates rather than a linear index, so hits address grids of more than `u32::MAX` voxels.
+const HIT_SIZE: u64 = 16;
+/// Voxel values are accumulated as integers in units of `1 / FIXED_POINT_SCALE`, so sums don't
+/// depend on the order rays add to them. A dense `u32` sum wraps past
+/// `u32::MAX / FIXED_POINT_SCALE`, just under 1,048,576, per voxel and frame.
+const FIXED_POINT_SCALE: u32 = 4096;
+/// Hits the first sparse readback covers, before any count has been read.
+const INITIAL_READBACK_HITS: u32 = 4096;
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
 struct ProcessingLabel;
@@ -36,12 +77,27 @@ impl Plugin for ImageProcessingPlugin {
             ExtractResourcePlugin::<DisplayTexture>::default(),
             ExtractResourcePlugin::<FrameInfo>::default(),
             ExtractResourcePlugin::<VoxelInfo>::default(),
-            ExtractResourcePlugin::<VoxelGridTexture>::default(),
+            ExtractResourcePlugin::<VoxelGridBuffer>::default(),
+            ExtractResourcePlugin::<HitBuffer>::default(),
+            ExtractResourcePlugin::<MotionDetectionSettings>::default(),
         ))
-        .add_systems(Startup, setup)
-        .add_event::<VoxelHitEvent>();
+        .insert_resource(self.marking)
+        .insert_resource(motion_settings_from_env())
+        .add_event::<VoxelFrameEvent>();
+        match self.marking {
+            MarkingMode::Dense => {
+                app.add_systems(
+                    Update,
+                    resize_voxel_grid.run_if(resource_exists_and_changed::<VoxelInfo>),
+                );
+            }
+            MarkingMode::Sparse { .. } => {
+                app.add_systems(Startup, create_hit_buffer);
+            }
+        }
         let render_app = app.sub_app_mut(RenderApp);
         render_app
+            .insert_resource(self.marking)
             .add_systems(RenderStartup, init_processing_pipeline)
             .add_systems(
                 Render,
@@ -53,55 +109,254 @@ impl Plugin for ImageProcessingPlugin {
     }
 }
 
-const TEST_VOXEL_SIZE: u32 = 10;
-const PADDED_WIDTH: usize = 64;
-fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
-    let size = Extent3d {
-        width: TEST_VOXEL_SIZE,
-        height: TEST_VOXEL_SIZE,
-        depth_or_array_layers: TEST_VOXEL_SIZE,
-    };
+/// Default settings, with the threshold taken from [`THRESHOLD_VAR`] if it holds a number.
+fn motion_settings_from_env() -> MotionDetectionSettings {
+    let mut settings = MotionDetectionSettings::default();
+    if let Ok(value) = std::env::var(THRESHOLD_VAR) {
+        match value.parse() {
+            Ok(threshold) => settings.threshold = threshold,
+            Err(err) => warn!("ignoring {THRESHOLD_VAR}={value:?}: {err}"),
+        }
+    }
+    settings
+}
 
-    let mut image = Image::new_uninit(
-        size,
-        TextureDimension::D3,
-        TextureFormat::R32Float,
-        RenderAssetUsages::RENDER_WORLD,
-    );
-    image.texture_descriptor.usage |= TextureUsages::COPY_SRC | TextureUsages::STORAGE_BINDING;
-    let image = images.add(image);
+#[derive(Component)]
+struct VoxelReadback;
+
+/// (Re)creates the voxel grid buffer and its readback whenever the server's grid changes size.
+fn resize_voxel_grid(
+    mut commands: Commands,
+    mut buffers: ResMut<Assets<ShaderStorageBuffer>>,
+    voxel_info: Res<VoxelInfo>,
+    voxel_grid: Option<Res<VoxelGridBuffer>>,
+    readbacks: Query<Entity, With<VoxelReadback>>,
+    render_device: Res<RenderDevice>,
+) {
+    if voxel_grid.is_some_and(|grid| grid.dims == voxel_info.dims) {
+        return;
+    }
+    for entity in &readbacks {
+        commands.entity(entity).despawn();
+    }
+    // Without a buffer nothing is marked, rather than the device failing to bind one too large.
+    commands.remove_resource::<VoxelGridBuffer>();
 
-    commands.insert_resource(VoxelGridTexture(image.clone()));
+    let limits = render_device.limits();
+    let max_size = (limits.max_storage_buffer_binding_size as u64).min(limits.max_buffer_size);
+    let size = voxel_count(voxel_info.dims)
+        .and_then(|voxels| voxels.checked_mul(size_of::<u32>() as u64))
+        .and_then(|bytes| bytes.checked_add(FRAME_HEADER_SIZE))
+        .filter(|&size| size <= max_size);
+    let Some(size) = size else {
+        error!(
+            "grid {} of {} voxels is too large for a dense buffer of at most {max_size} bytes, \
+             use a smaller grid or {MARKING_VAR}=sparse",
+            voxel_info.grid_id, voxel_info.dims
+        );
+        return;
+    };
+    let mut buffer = ShaderStorageBuffer::with_size(size as usize, RenderAssetUsages::RENDER_WORLD);
+    buffer.buffer_description.usage |= BufferUsages::COPY_SRC | BufferUsages::COPY_DST;
+    let handle = buffers.add(buffer);
+
+    commands.insert_resource(VoxelGridBuffer {
+        handle: handle.clone(),
+        dims: voxel_info.dims,
+    });
     commands
-        .spawn(Readback::texture(image.clone()))
+        .spawn((VoxelReadback, Readback::buffer(handle)))
         .observe(on_voxel_readback);
-    info!("set up");
+    info!("voxel grid buffer resized to {}", voxel_info.dims);
 }
 
-pub fn on_voxel_readback(trigger: On<ReadbackComplete>, mut events: EventWriter<VoxelHitEvent>) {
-    let diff: Vec<f32> = trigger.event().to_shader_type();
-
-    let width = TEST_VOXEL_SIZE as usize;
-    let height = TEST_VOXEL_SIZE as usize;
-    let depth = TEST_VOXEL_SIZE as usize;
-
-    for z in 0..depth {
-        for y in 0..height {
-            for x in 0..width {
-                let index = z * (height * PADDED_WIDTH) + y * PADDED_WIDTH + x;
-                let f = diff[index];
-                if f > 0.0 {
-                    info!("voxel ({}, {}, {}) = {}", x, y, z, f);
-                    let voxel = Voxel {
-                        x: x as u32,
-                        y: y as u32,
-                        z: z as u32,
-                    };
-                    events.write(VoxelHitEvent { voxel, value: f });
-                }
-            }
+/// Voxels in a grid of `dims` voxels, if they can be counted in a `u64`.
+fn voxel_count(dims: UVec3) -> Option<u64> {
+    (dims.x as u64)
+        .checked_mul(dims.y as u64)
+        .and_then(|voxels| voxels.checked_mul(dims.z as u64))
+}
+
+/// The voxel at linear index `id` of a grid of `dims` voxels, x varying fastest.
+fn voxel_from_id(id: u32, dims: UVec3) -> Voxel {
+    Voxel {
+        x: id % dims.x,
+        y: id / dims.x % dims.y,
+        z: id / (dims.x * dims.y),
+    }
+}
+
+/// The frame header written ahead of a frame's marks, so a readback can be stamped with the frame
+/// that produced it rather than the one current when it completes.
+fn frame_header(frame_id: u64, captured_at: Timestamp) -> [u8; FRAME_HEADER_SIZE as usize] {
+    let mut header = [0; FRAME_HEADER_SIZE as usize];
+    header[..8].copy_from_slice(&frame_id.to_le_bytes());
+    header[8..].copy_from_slice(&captured_at.to_micros_since_unix_epoch().to_le_bytes());
+    header
+}
+
+/// The frame id and capture time in the header at the start of read back `data`.
+fn read_frame_header(data: &[u8]) -> Option<(u64, Timestamp)> {
+    let (frame_id, rest) = data.split_first_chunk::<8>()?;
+    let (captured_at, _) = rest.split_first_chunk::<8>()?;
+    Some((
+        u64::from_le_bytes(*frame_id),
+        Timestamp::from_micros_since_unix_epoch(i64::from_le_bytes(*captured_at)),
+    ))
+}
+
+fn to_fixed_point(value: f32) -> u64 {
+    (value * FIXED_POINT_SCALE as f32).round() as u64
+}
+
+fn from_fixed_point(value: u64) -> f32 {
+    value as f32 / FIXED_POINT_SCALE as f32
+}
+
+pub fn on_voxel_readback(
+    trigger: On<ReadbackComplete>,
+    mut events: EventWriter<VoxelFrameEvent>,
+    voxel_grid: Res<VoxelGridBuffer>,
+    voxel_info: Res<VoxelInfo>,
+) {
+    let data = &trigger.event().data;
+    let Some((frame_id, captured_at)) = read_frame_header(data) else {
+        return;
+    };
+    let sums = data[FRAME_HEADER_SIZE as usize..].chunks_exact(size_of::<u32>());
+    if sums.len() < voxel_grid.dims.element_product() as usize {
+        // Finished reading a buffer that has since been replaced by a differently sized one.
+        return;
+    }
+
+    let hits: Vec<VoxelHit> = sums
+        .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        .enumerate()
+        .filter(|&(_, sum)| sum > 0)
+        .map(|(id, sum)| VoxelHit {
+            voxel: voxel_from_id(id as u32, voxel_grid.dims),
+            value: from_fixed_point(sum as u64),
+        })
+        .collect();
+    if !hits.is_empty() {
+        events.write(VoxelFrameEvent {
+            grid_id: voxel_info.grid_id,
+            frame_id,
+            captured_at,
+            hits,
+        });
+    }
+}
+
+/// Creates the [`HitBuffer`] for [`MarkingMode::Sparse`] and the readback of its first hits.
+fn create_hit_buffer(
+    mut commands: Commands,
+    mut buffers: ResMut<Assets<ShaderStorageBuffer>>,
+    marking: Res<MarkingMode>,
+) {
+    let MarkingMode::Sparse { capacity } = *marking else {
+        return;
+    };
+    let size = FRAME_HEADER_SIZE + HIT_COUNT_SIZE + HIT_SIZE * capacity as u64;
+    let mut buffer = ShaderStorageBuffer::with_size(size as usize, RenderAssetUsages::RENDER_WORLD);
+    buffer.buffer_description.usage |= BufferUsages::COPY_SRC | BufferUsages::COPY_DST;
+    let handle = buffers.add(buffer);
+
+    commands.insert_resource(HitBuffer {
+        handle: handle.clone(),
+        capacity,
+    });
+    commands
+        .spawn((
+            VoxelReadback,
+            Readback::buffer_range(
+                handle,
+                0,
+                hit_readback_size(INITIAL_READBACK_HITS, capacity),
+            ),
+        ))
+        .observe(on_hit_readback);
+}
+
+/// Bytes of the [`HitBuffer`] to read back to get its header, count and `hits` hits.
+fn hit_readback_size(hits: u32, capacity: u32) -> u64 {
+    FRAME_HEADER_SIZE + HIT_COUNT_SIZE + HIT_SIZE * hits.min(capacity) as u64
+}
+
+/// Sums the hits appended to the [`HitBuffer`] per voxel and resizes the readback to cover as many
+/// hits as this frame produced, since the next frame is likely to produce about as many.
+pub fn on_hit_readback(
+    trigger: On<ReadbackComplete>,
+    mut events: EventWriter<VoxelFrameEvent>,
+    mut readbacks: Query<&mut Readback, With<VoxelReadback>>,
+    hit_buffer: Res<HitBuffer>,
+    voxel_info: Option<Res<VoxelInfo>>,
+) {
+    let data = &trigger.event().data;
+    let Some((frame_id, captured_at)) = read_frame_header(data) else {
+        return;
+    };
+    let data = &data[FRAME_HEADER_SIZE as usize..];
+    let Some(count) = data.first_chunk().map(|bytes| u32::from_le_bytes(*bytes)) else {
+        return;
+    };
+    let appended = count.min(hit_buffer.capacity);
+    if count > hit_buffer.capacity {
+        warn!(
+            "dropped {} voxel hits past the hit buffer's capacity of {}",
+            count - hit_buffer.capacity,
+            hit_buffer.capacity
+        );
+    }
+    let read = (data.len() as u64 - HIT_COUNT_SIZE) / HIT_SIZE;
+    if (appended as u64) > read {
+        // Hits past the readback's end are lost for this frame; cover them from the next one on.
+        for mut readback in &mut readbacks {
+            *readback = Readback::buffer_range(
+                hit_buffer.handle.clone(),
+                0,
+                hit_readback_size(appended, hit_buffer.capacity),
+            );
         }
     }
+    let Some(voxel_info) = voxel_info else {
+        return;
+    };
+
+    // Hits arrive in whatever order the GPU appended them, so they are summed in fixed point to
+    // make the sums independent of it.
+    let mut sums: HashMap<UVec3, u64> = HashMap::new();
+    let hits = data[HIT_COUNT_SIZE as usize..].chunks_exact(HIT_SIZE as usize);
+    for hit in hits.take(appended as usize) {
+        let word = |i: usize| [hit[i], hit[i + 1], hit[i + 2], hit[i + 3]];
+        let voxel = UVec3::new(
+            u32::from_le_bytes(word(0)),
+            u32::from_le_bytes(word(4)),
+            u32::from_le_bytes(word(8)),
+        );
+        let value = f32::from_le_bytes(word(12));
+        *sums.entry(voxel).or_default() += to_fixed_point(value);
+    }
+    let hits: Vec<VoxelHit> = sums
+        .into_iter()
+        .filter(|&(_, sum)| sum > 0)
+        .map(|(voxel, sum)| VoxelHit {
+            voxel: Voxel {
+                x: voxel.x,
+                y: voxel.y,
+                z: voxel.z,
+            },
+            value: from_fixed_point(sum),
+        })
+        .collect();
+    if !hits.is_empty() {
+        events.write(VoxelFrameEvent {
+            grid_id: voxel_info.grid_id,
+            frame_id,
+            captured_at,
+            hits,
+        });
+    }
 }
 
 fn init_processing_pipeline(
@@ -109,6 +364,7 @@ fn init_processing_pipeline(
     render_device: Res<RenderDevice>,
     asset_server: Res<AssetServer>,
     pipeline_cache: Res<PipelineCache>,
+    marking: Res<MarkingMode>,
 ) {
     let texture_bind_group_layout = render_device.create_bind_group_layout(
         "DifferenceMask",
@@ -118,9 +374,18 @@ fn init_processing_pipeline(
                 texture_storage_2d(TextureFormat::Rgba8Unorm, StorageTextureAccess::ReadOnly),
                 texture_storage_2d(TextureFormat::Rgba8Unorm, StorageTextureAccess::ReadOnly),
                 texture_storage_2d(TextureFormat::Rgba8Unorm, StorageTextureAccess::ReadWrite),
+                uniform_buffer::<MotionDetectionSettings>(false),
+                storage_buffer::<u32>(false),
             ),
         ),
     );
+    let changed_pixels = render_device.create_buffer(&BufferDescriptor {
+        label: Some("changed_pixels"),
+        size: size_of::<u32>() as u64,
+        usage: BufferUsages::STORAGE | BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+    // Both marking modes write to a storage buffer: per-voxel sums or appended hits.
     let raymarch_bind_group_layout = render_device.create_bind_group_layout(
         "Raymarch",
         &BindGroupLayoutEntries::sequential(
@@ -128,14 +393,23 @@ fn init_processing_pipeline(
             (
                 texture_storage_2d(TextureFormat::Rgba8Unorm, StorageTextureAccess::ReadWrite),
                 uniform_buffer::<RaymarchUniforms>(false),
-                texture_storage_3d(TextureFormat::R32Float, StorageTextureAccess::ReadWrite),
+                storage_buffer_sized(false, None),
             ),
         ),
     );
+    let mut shader_defs = vec![ShaderDefVal::UInt(
+        "FIXED_POINT_SCALE".into(),
+        FIXED_POINT_SCALE,
+    )];
+    if let MarkingMode::Sparse { .. } = *marking {
+        shader_defs.push("SPARSE_HITS".into());
+    }
+    // Both entry points live in one module, so both pipelines need every def it uses.
     let shader = asset_server.load(SHADER_ASSET_PATH);
     let diff_pipeline = pipeline_cache.queue_compute_pipeline(ComputePipelineDescriptor {
         layout: vec![texture_bind_group_layout.clone()],
         shader: shader.clone(),
+        shader_defs: shader_defs.clone(),
         entry_point: Some(Cow::from("diff")),
         zero_initialize_workgroup_memory: true,
         ..default()
@@ -146,6 +420,7 @@ fn init_processing_pipeline(
             raymarch_bind_group_layout.clone(),
         ],
         shader: shader.clone(),
+        shader_defs,
         entry_point: Some(Cow::from("raymarch")),
         zero_initialize_workgroup_memory: true,
         ..default()
@@ -156,37 +431,65 @@ fn init_processing_pipeline(
         diff_pipeline,
         raymarch_bind_group_layout,
         raymarch_pipeline,
+        changed_pixels,
     });
 }
 
 #[derive(ShaderType)]
 struct RaymarchUniforms {
-    camera_pos: Vec3,
-    camera_rotation: Mat3,
+    /// See [`FrameInfo::camera_to_world`].
+    camera_to_world: Mat4,
     screen_size: Vec2,
-    grid_center: Vec3,
-    voxel_n: i32,
+    grid_origin: Vec3,
+    /// Rotation from world axes to the grid's local axes.
+    grid_rotation: Mat3,
+    grid_dims: IVec3,
     voxel_size: f32,
     focal_length: f32,
-    changed_threshold: f32,
 }
 fn prepare_bind_group(
     mut commands: Commands,
     pipeline: Res<ProcessingPipeline>,
     gpu_images: Res<RenderAssets<GpuImage>>,
+    gpu_buffers: Res<RenderAssets<GpuShaderStorageBuffer>>,
     camera_images: Res<CameraTextures>,
     display_texture: Res<DisplayTexture>,
-    voxel_grid: Res<VoxelGridTexture>,
-    voxel_info: Res<VoxelInfo>,
+    marking: Res<MarkingMode>,
+    voxel_grid: Option<Res<VoxelGridBuffer>>,
+    hit_buffer: Option<Res<HitBuffer>>,
+    voxel_info: Option<Res<VoxelInfo>>,
     frame_info: Res<FrameInfo>,
+    motion_settings: Res<MotionDetectionSettings>,
     render_device: Res<RenderDevice>,
     queue: Res<RenderQueue>,
 ) {
     let current = gpu_images.get(&camera_images.current).unwrap();
     let prev = gpu_images.get(&camera_images.prev).unwrap();
     let target = gpu_images.get(&display_texture.handle).unwrap();
-    let voxel = gpu_images.get(&voxel_grid.0).unwrap();
+    // Nothing to raymarch into until the server's grid arrives and what hits are marked in is
+    // uploaded.
+    let Some(voxel_info) = voxel_info else {
+        commands.remove_resource::<ProcessingBindGroup>();
+        return;
+    };
+    let marked = match *marking {
+        MarkingMode::Dense => voxel_grid.map(|grid| grid.handle.clone()),
+        MarkingMode::Sparse { .. } => hit_buffer.map(|buffer| buffer.handle.clone()),
+    };
+    let Some(marked) = marked.and_then(|handle| gpu_buffers.get(&handle)) else {
+        commands.remove_resource::<ProcessingBindGroup>();
+        return;
+    };
+    // Queued writes land before this frame's commands run, so the header describes the frame
+    // whose marks follow it.
+    queue.write_buffer(
+        &marked.buffer,
+        0,
+        &frame_header(camera_images.frame_id, camera_images.captured_at),
+    );
 
+    let mut settings_buffer = UniformBuffer::from(motion_settings.clone());
+    settings_buffer.write_buffer(&render_device, &queue);
     let bind_group_0 = render_device.create_bind_group(
         None,
         &pipeline.texture_bind_group_layout,
@@ -194,34 +497,34 @@ fn prepare_bind_group(
             &current.texture_view,
             &prev.texture_view,
             &target.texture_view,
+            &settings_buffer,
+            pipeline.changed_pixels.as_entire_binding(),
         )),
     );
-    let focal_length = (current.size_2d().x as f32 * 0.5) / (frame_info.fov * 0.5).tan();
+    let focal_length = frame_info.focal_length(current.size_2d().x as f32);
     let screen_size = target.size_2d();
     let size = Vec2 {
         x: screen_size.x as f32,
         y: screen_size.y as f32,
     };
     let mut uniform_buffer = UniformBuffer::from(RaymarchUniforms {
-        camera_pos: frame_info.camera_position,
-        camera_rotation: Mat3::from_euler(
-            EulerRot::YXZ,
-            frame_info.yaw,
-            frame_info.pitch,
-            frame_info.roll,
-        ),
+        camera_to_world: frame_info.camera_to_world(),
         screen_size: size,
-        grid_center: voxel_info.grid_center,
-        voxel_n: voxel_info.n as i32,
+        grid_origin: voxel_info.origin,
+        grid_rotation: Mat3::from_quat(voxel_info.orientation.inverse()),
+        grid_dims: voxel_info.dims.as_ivec3(),
         voxel_size: voxel_info.voxel_size,
         focal_length,
-        changed_threshold: 0.05,
     });
     uniform_buffer.write_buffer(&render_device, &queue);
     let bind_group_1 = render_device.create_bind_group(
         None,
         &pipeline.raymarch_bind_group_layout,
-        &BindGroupEntries::sequential((&target.texture_view, &uniform_buffer, &voxel.texture_view)),
+        &BindGroupEntries::sequential((
+            &target.texture_view,
+            &uniform_buffer,
+            marked.buffer.as_entire_binding(),
+        )),
     );
     commands.insert_resource(ProcessingBindGroup([bind_group_0, bind_group_1]));
 }
@@ -276,10 +579,35 @@ impl Node for ProcessingNode {
         render_context: &mut bevy::render::renderer::RenderContext,
         world: &World,
     ) -> Result<(), render_graph::NodeRunError> {
-        let bind_group = &world.resource::<ProcessingBindGroup>().0;
+        let Some(ProcessingBindGroup(bind_group)) = world.get_resource::<ProcessingBindGroup>()
+        else {
+            return Ok(());
+        };
         let pipeline_cache = world.resource::<PipelineCache>();
         let pipeline = world.resource::<ProcessingPipeline>();
         let images = world.resource::<CameraTextures>();
+        // Every frame starts from empty marks, so each readback holds exactly that frame's hits
+        // and frames without new camera input read back none. Integrating over time is left to
+        // the server. The frame header ahead of the marks is kept.
+        let gpu_buffers = world.resource::<RenderAssets<GpuShaderStorageBuffer>>();
+        let marks = match *world.resource::<MarkingMode>() {
+            MarkingMode::Dense => world
+                .get_resource::<VoxelGridBuffer>()
+                .map(|grid| (&grid.handle, None)),
+            MarkingMode::Sparse { .. } => world
+                .get_resource::<HitBuffer>()
+                .map(|hits| (&hits.handle, Some(HIT_COUNT_SIZE))),
+        };
+        if let Some((handle, size)) = marks
+            && let Some(buffer) = gpu_buffers.get(handle)
+        {
+            render_context
+                .command_encoder()
+                .clear_buffer(&buffer.buffer, FRAME_HEADER_SIZE, size);
+        }
+        render_context
+            .command_encoder()
+            .clear_buffer(&pipeline.changed_pixels, 0, None);
         if !images.new_frame {
             info!("No new frame");
             return Ok(());
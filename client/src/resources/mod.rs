@@ -2,8 +2,11 @@ use crate::module_bindings::*;
 use crate::prelude::*;
 use bevy::render::{
     extract_resource::ExtractResource,
-    render_resource::{BindGroup, BindGroupLayout, CachedComputePipelineId},
+    render_resource::{BindGroup, BindGroupLayout, Buffer, CachedComputePipelineId, ShaderType},
+    storage::ShaderStorageBuffer,
 };
+use spacetimedb_sdk::Timestamp;
+use std::collections::VecDeque;
 
 #[derive(Resource, Clone, ExtractResource)]
 pub struct CameraTextures {
@@ -11,6 +14,10 @@ pub struct CameraTextures {
     pub prev: Handle<Image>,
     pub size: IVec2,
     pub new_frame: bool,
+    /// Number of the latest frame in `current`, counting up from 1 as frames are captured.
+    pub frame_id: u64,
+    /// When the latest frame was grabbed from the camera.
+    pub captured_at: Timestamp,
 }
 
 #[derive(Resource, Clone, ExtractResource)]
@@ -24,32 +31,369 @@ pub struct ProcessingPipeline {
     pub raymarch_bind_group_layout: BindGroupLayout,
     pub diff_pipeline: CachedComputePipelineId,
     pub raymarch_pipeline: CachedComputePipelineId,
+    /// Atomic `u32` the diff pass counts changed pixels in.
+    pub changed_pixels: Buffer,
 }
 
 #[derive(Resource)]
 pub struct ProcessingBindGroup(pub [BindGroup; 2]);
 
+/// Storage buffer the raymarch pass accumulates into in [`MarkingMode::Dense`]: a header naming the
+/// frame the marks came from, then one `u32` per voxel of the grid, in fixed point and indexed x
+/// fastest. The marks are cleared at the start of every frame.
 #[derive(Resource, ExtractResource, Clone)]
-pub struct VoxelGridTexture(pub Handle<Image>);
+pub struct VoxelGridBuffer {
+    pub handle: Handle<ShaderStorageBuffer>,
+    pub dims: UVec3,
+}
+
+/// What counts as motion between two frames. Changes take effect from the next frame, so these can
+/// be tuned while the camera runs.
+#[derive(Resource, ExtractResource, ShaderType, Clone, Debug)]
+pub struct MotionDetectionSettings {
+    /// Weights of the red, green and blue channels in a pixel's grayscale value.
+    pub grayscale_weights: Vec3,
+    /// Least change in grayscale value, between 0 and 1, for a pixel to count as changed.
+    pub threshold: f32,
+    /// Frames with fewer changed pixels than this are taken to be noise and cast no rays.
+    pub min_changed_pixels: u32,
+    /// Most voxels a single ray marks.
+    pub max_ray_steps: u32,
+}
+
+impl Default for MotionDetectionSettings {
+    fn default() -> Self {
+        Self {
+            // Rec. 601 luma.
+            grayscale_weights: vec3(0.299, 0.587, 0.114),
+            threshold: 0.05,
+            min_changed_pixels: 0,
+            max_ray_steps: 64,
+        }
+    }
+}
+
+/// How the raymarch pass marks the voxels its rays cross.
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum MarkingMode {
+    /// Values accumulate in a buffer covering the whole grid, which is read back in full.
+    #[default]
+    Dense,
+    /// Each marked voxel is appended to a storage buffer behind an atomic counter and only as many
+    /// hits as were appended are read back. Hits past `capacity` in a frame are dropped.
+    Sparse { capacity: u32 },
+}
 
+/// Storage buffer the raymarch pass appends hits to in [`MarkingMode::Sparse`]: a header naming the
+/// frame the hits came from and a `u32` count, followed by up to `capacity` hits of a voxel's `u32`
+/// coordinates and an `f32` value.
+#[derive(Resource, ExtractResource, Clone)]
+pub struct HitBuffer {
+    pub handle: Handle<ShaderStorageBuffer>,
+    pub capacity: u32,
+}
+
+/// The camera's pose and field of view.
+///
+/// World space follows Bevy: right-handed with +Y up. In camera space the camera sits at the
+/// origin looking down -Z, with +X to the right of the image and +Y towards its top. The camera is
+/// yawed about world Y, then pitched about its own X axis, then rolled about its own Z axis and
+/// moved to `camera_position`, so a camera with all angles zero looks down world -Z.
 #[derive(Resource, Default, ExtractResource, Clone)]
 pub struct FrameInfo {
+    /// World position of the camera's optical center.
     pub camera_position: Vec3,
+    /// Radians about the camera's Y axis, positive turning left.
     pub yaw: f32,
+    /// Radians about the camera's X axis, positive tilting up.
     pub pitch: f32,
+    /// Radians about the camera's Z axis, positive rolling counter-clockwise as seen from behind.
     pub roll: f32,
+    /// Horizontal field of view in degrees.
     pub fov: f32,
 }
 
-#[derive(Resource, Default, ExtractResource, Clone)]
+impl FrameInfo {
+    /// Rotation from camera space to world space.
+    pub fn rotation(&self) -> Quat {
+        Quat::from_euler(EulerRot::YXZ, self.yaw, self.pitch, self.roll)
+    }
+
+    /// Transform from camera space to world space.
+    pub fn camera_to_world(&self) -> Mat4 {
+        Mat4::from_rotation_translation(self.rotation(), self.camera_position)
+    }
+
+    /// Focal length in pixels for an image `width` pixels wide.
+    pub fn focal_length(&self, width: f32) -> f32 {
+        (width * 0.5) / (self.fov.to_radians() * 0.5).tan()
+    }
+}
+
+/// Geometry of the server's voxel grid that this camera marks voxels in.
+#[derive(Resource, ExtractResource, Clone)]
 pub struct VoxelInfo {
-    pub n: u32,
+    pub grid_id: u32,
+    pub dims: UVec3,
+    /// World position of the grid's minimum corner.
+    pub origin: Vec3,
     pub voxel_size: f32,
-    pub grid_center: Vec3,
+    /// Rotation from the grid's local axes to world axes.
+    pub orientation: Quat,
+}
+
+impl From<Vec3> for Vector3 {
+    fn from(v: Vec3) -> Self {
+        Self {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+
+impl From<Quat> for Quaternion {
+    fn from(q: Quat) -> Self {
+        Self {
+            x: q.x,
+            y: q.y,
+            z: q.z,
+            w: q.w,
+        }
+    }
+}
+
+impl From<&VoxelGrid> for VoxelInfo {
+    fn from(grid: &VoxelGrid) -> Self {
+        Self {
+            grid_id: grid.id,
+            dims: uvec3(grid.nx, grid.ny, grid.nz),
+            origin: vec3(grid.origin.x, grid.origin.y, grid.origin.z),
+            voxel_size: grid.voxel_size,
+            orientation: Quat::from_xyzw(
+                grid.orientation.x,
+                grid.orientation.y,
+                grid.orientation.z,
+                grid.orientation.w,
+            ),
+        }
+    }
 }
 
+/// Every voxel hit found in one readback of the voxel grid.
 #[derive(Event, BufferedEvent)]
-pub struct VoxelHitEvent {
-    pub voxel: Voxel,
-    pub value: f32,
+pub struct VoxelFrameEvent {
+    pub grid_id: u32,
+    pub frame_id: u64,
+    pub captured_at: Timestamp,
+    pub hits: Vec<VoxelHit>,
+}
+
+/// One `ping` round trip: our clock when it was sent and received, and the server's in between.
+#[derive(Clone, Copy, Debug)]
+pub struct ClockSample {
+    pub sent_micros: i64,
+    pub received_micros: i64,
+    pub server_micros: i64,
+}
+
+impl ClockSample {
+    pub fn round_trip_micros(&self) -> i64 {
+        self.received_micros - self.sent_micros
+    }
+
+    /// Local time the server's timestamp is assumed to correspond to.
+    fn midpoint_micros(&self) -> i64 {
+        self.sent_micros + self.round_trip_micros() / 2
+    }
+
+    fn offset_micros(&self) -> i64 {
+        self.server_micros - self.midpoint_micros()
+    }
+}
+
+/// Offset of the server's clock from ours, as a line through recent ping samples.
+#[derive(Clone, Copy, Debug)]
+pub struct ClockEstimate {
+    /// Local time, in microseconds since the epoch, the offset was fitted around.
+    pub reference_micros: i64,
+    /// Microseconds to add to local time at `reference_micros` to get server time.
+    pub offset_micros: f64,
+    /// Change of the offset in microseconds per second of local time, i.e. parts per million.
+    pub drift_ppm: f64,
+    /// Shortest round trip among the samples.
+    pub round_trip_micros: i64,
+}
+
+impl ClockEstimate {
+    pub fn offset_at(&self, local_micros: i64) -> f64 {
+        let elapsed = (local_micros - self.reference_micros) as f64 / 1_000_000.0;
+        self.offset_micros + self.drift_ppm * elapsed
+    }
+}
+
+/// Estimates the server's clock from `ping` round trips, so capture timestamps can be sent in
+/// server time.
+#[derive(Resource, Default)]
+pub struct ClockSync {
+    samples: VecDeque<ClockSample>,
+    estimate: Option<ClockEstimate>,
+}
+
+impl ClockSync {
+    const MAX_SAMPLES: usize = 64;
+
+    pub fn add_sample(&mut self, sample: ClockSample) {
+        if sample.round_trip_micros() < 0 {
+            return;
+        }
+        if self.samples.len() == Self::MAX_SAMPLES {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+        self.estimate = self.fit();
+    }
+
+    pub fn estimate(&self) -> Option<ClockEstimate> {
+        self.estimate
+    }
+
+    /// Converts a local timestamp to server time. Unchanged until the first sample arrives.
+    pub fn to_server_time(&self, local: Timestamp) -> Timestamp {
+        let local_micros = local.to_micros_since_unix_epoch();
+        match self.estimate {
+            Some(estimate) => Timestamp::from_micros_since_unix_epoch(
+                local_micros + estimate.offset_at(local_micros).round() as i64,
+            ),
+            None => local,
+        }
+    }
+
+    /// Weighted least-squares line through the samples' offsets. Slow round trips leave more room
+    /// for asymmetric delays, so they count for less.
+    fn fit(&self) -> Option<ClockEstimate> {
+        let round_trip_micros = self
+            .samples
+            .iter()
+            .map(ClockSample::round_trip_micros)
+            .min()?;
+        let reference_micros = self.samples.back()?.midpoint_micros();
+        let points: Vec<(f64, f64, f64)> = self
+            .samples
+            .iter()
+            .map(|sample| {
+                let x = (sample.midpoint_micros() - reference_micros) as f64 / 1_000_000.0;
+                let y = sample.offset_micros() as f64;
+                let w = 1.0 / (sample.round_trip_micros() as f64 + 1000.0).powi(2);
+                (x, y, w)
+            })
+            .collect();
+        let total: f64 = points.iter().map(|&(_, _, w)| w).sum();
+        let mean_x = points.iter().map(|&(x, _, w)| w * x).sum::<f64>() / total;
+        let mean_y = points.iter().map(|&(_, y, w)| w * y).sum::<f64>() / total;
+        let sxx: f64 = points
+            .iter()
+            .map(|&(x, _, w)| w * (x - mean_x).powi(2))
+            .sum();
+        let sxy: f64 = points
+            .iter()
+            .map(|&(x, y, w)| w * (x - mean_x) * (y - mean_y))
+            .sum();
+        // Drift only shows over a spread of samples; until then assume there is none.
+        let drift_ppm = if self.samples.len() >= 2 && sxx > 1e-12 {
+            sxy / sxx
+        } else {
+            0.0
+        };
+        Some(ClockEstimate {
+            reference_micros,
+            offset_micros: mean_y - drift_ppm * mean_x,
+            drift_ppm,
+            round_trip_micros,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const START_MICROS: i64 = 1_700_000_000_000_000;
+    const OFFSET_MICROS: f64 = 250_000.0;
+    const DRIFT_PPM: f64 = 50.0;
+
+    /// The server's clock at local time `local_micros`.
+    fn server_micros(local_micros: i64) -> f64 {
+        local_micros as f64
+            + OFFSET_MICROS
+            + DRIFT_PPM * (local_micros - START_MICROS) as f64 / 1_000_000.0
+    }
+
+    /// A ping sent at `sent_micros` that takes `up_micros` to reach the server and `down_micros`
+    /// to come back.
+    fn sample(sent_micros: i64, up_micros: i64, down_micros: i64) -> ClockSample {
+        ClockSample {
+            sent_micros,
+            received_micros: sent_micros + up_micros + down_micros,
+            server_micros: server_micros(sent_micros + up_micros).round() as i64,
+        }
+    }
+
+    #[test]
+    fn fit_recovers_offset_and_drift() {
+        let mut clock = ClockSync::default();
+        for i in 0..20 {
+            let sent_micros = START_MICROS + i * 2_000_000;
+            // Every third ping is slow and lopsided, which puts its midpoint 90 ms off.
+            let (up_micros, down_micros) = if i % 3 == 2 {
+                (190_000, 10_000)
+            } else {
+                (1_000, 1_000)
+            };
+            clock.add_sample(sample(sent_micros, up_micros, down_micros));
+        }
+
+        let estimate = clock.estimate().unwrap();
+        assert_eq!(estimate.round_trip_micros, 2_000);
+        assert!(
+            (estimate.drift_ppm - DRIFT_PPM).abs() < 0.5,
+            "drift {} ppm",
+            estimate.drift_ppm
+        );
+        let local_micros = START_MICROS + 45_000_000;
+        let offset_micros = server_micros(local_micros) - local_micros as f64;
+        assert!(
+            (estimate.offset_at(local_micros) - offset_micros).abs() < 50.0,
+            "offset {} µs, expected {offset_micros} µs",
+            estimate.offset_at(local_micros)
+        );
+    }
+
+    #[test]
+    fn single_sample_assumes_no_drift() {
+        let mut clock = ClockSync::default();
+        clock.add_sample(sample(START_MICROS, 1_000, 1_000));
+        let estimate = clock.estimate().unwrap();
+        assert_eq!(estimate.drift_ppm, 0.0);
+        assert_eq!(estimate.offset_at(START_MICROS), OFFSET_MICROS);
+    }
+
+    #[test]
+    fn server_time_is_local_time_without_an_estimate() {
+        let clock = ClockSync::default();
+        assert!(clock.estimate().is_none());
+        let local = Timestamp::from_micros_since_unix_epoch(START_MICROS);
+        assert_eq!(clock.to_server_time(local), local);
+    }
+
+    #[test]
+    fn server_time_applies_the_estimate() {
+        let mut clock = ClockSync::default();
+        clock.add_sample(sample(START_MICROS, 1_000, 1_000));
+        let local = Timestamp::from_micros_since_unix_epoch(START_MICROS + 1_000);
+        assert_eq!(
+            clock.to_server_time(local).to_micros_since_unix_epoch(),
+            START_MICROS + 1_000 + OFFSET_MICROS as i64
+        );
+    }
 }
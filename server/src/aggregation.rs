@@ -0,0 +1,87 @@
+use spacetimedb::{ReducerContext, SpacetimeType};
+
+use crate::auth::{self, Role};
+use crate::{clear_voxel_values, voxel_grid};
+
+/// How observations of a voxel combine into its value.
+#[derive(SpacetimeType, Clone, Copy, PartialEq, Debug)]
+pub enum AggregationMode {
+    /// Hit values, scaled by camera weight, are summed.
+    Additive,
+    /// The value is the log-odds of the voxel being occupied. Hits raise it, misses lower it.
+    LogOdds(LogOddsConfig),
+}
+
+/// Log-odds increments per observation and the bounds the value is clamped to. Clamping keeps a
+/// voxel able to change its mind after a long run of identical observations.
+#[derive(SpacetimeType, Clone, Copy, PartialEq, Debug)]
+pub struct LogOddsConfig {
+    /// Added for each hit, positive.
+    pub hit: f32,
+    /// Added for each miss, negative.
+    pub miss: f32,
+    pub min: f32,
+    pub max: f32,
+}
+impl AggregationMode {
+    /// Change to a voxel's value for a hit of `value` from a camera of weight `weight`.
+    pub(crate) fn hit_delta(&self, value: f32, weight: f32) -> f32 {
+        match self {
+            AggregationMode::Additive => value * weight,
+            AggregationMode::LogOdds(config) => config.hit * weight,
+        }
+    }
+
+    /// Change to a voxel's value for a ray from a camera of weight `weight` passing through it,
+    /// or `None` if misses don't count.
+    pub(crate) fn miss_delta(&self, weight: f32) -> Option<f32> {
+        match self {
+            AggregationMode::Additive => None,
+            AggregationMode::LogOdds(config) => Some(config.miss * weight),
+        }
+    }
+
+    pub(crate) fn clamp(&self, value: f32) -> f32 {
+        match self {
+            AggregationMode::Additive => value,
+            AggregationMode::LogOdds(config) => value.clamp(config.min, config.max),
+        }
+    }
+}
+
+/// Sets how grid `grid_id` aggregates observations. Values in one mode mean nothing in the other,
+/// so switching modes discards the grid's values.
+#[spacetimedb::reducer]
+pub fn configure_aggregation(
+    ctx: &ReducerContext,
+    grid_id: u32,
+    mode: AggregationMode,
+) -> Result<(), String> {
+    auth::require_role(ctx, Role::Admin)?;
+    if let AggregationMode::LogOdds(config) = mode {
+        let finite = [config.hit, config.miss, config.min, config.max]
+            .iter()
+            .all(|v| v.is_finite());
+        if !(finite && config.hit > 0.0 && config.miss < 0.0) {
+            return Err("log-odds hit must be positive and miss negative".to_string());
+        }
+        if !(config.min < 0.0 && config.max > 0.0) {
+            return Err(format!(
+                "log-odds bounds must straddle zero, got [{}, {}]",
+                config.min, config.max
+            ));
+        }
+    }
+    let mut grid = ctx
+        .db
+        .voxel_grid()
+        .id()
+        .find(grid_id)
+        .ok_or_else(|| format!("no voxel grid with id {grid_id}"))?;
+    if std::mem::discriminant(&grid.aggregation) != std::mem::discriminant(&mode) {
+        clear_voxel_values(ctx, grid_id);
+    }
+    grid.aggregation = mode;
+    ctx.db.voxel_grid().id().update(grid);
+    Ok(())
+}
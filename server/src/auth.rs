@@ -0,0 +1,96 @@
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table, Timestamp, table};
+
+use crate::sessions;
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    /// May submit hits and keep its own registration and pose up to date.
+    Camera,
+    /// May only read. Reading needs no role, so this records intent rather than granting access.
+    Viewer,
+    /// May do anything, including reconfiguring grids and granting roles.
+    Admin,
+}
+
+/// The role each identity allowed to call mutating reducers holds. Identities without a row are
+/// read-only.
+#[table(name = authorized_identity, public)]
+pub struct AuthorizedIdentity {
+    #[primary_key]
+    pub identity: Identity,
+    pub role: Role,
+    pub granted_by: Identity,
+    pub granted_at: Timestamp,
+}
+
+/// Fails unless the caller holds `role`. Admins hold every role.
+pub(crate) fn require_role(ctx: &ReducerContext, role: Role) -> Result<(), String> {
+    match ctx.db.authorized_identity().identity().find(ctx.sender) {
+        Some(authorized) if authorized.role == role || authorized.role == Role::Admin => Ok(()),
+        _ => Err(format!("{} is not authorized as {role:?}", ctx.sender)),
+    }
+}
+
+/// Makes the module's owner, the caller of `init`, an admin if nobody is yet.
+pub(crate) fn bootstrap_admin(ctx: &ReducerContext) {
+    let has_admin = ctx
+        .db
+        .authorized_identity()
+        .iter()
+        .any(|authorized| authorized.role == Role::Admin);
+    if !has_admin {
+        log::info!("bootstrapping {} as admin", ctx.sender);
+        ctx.db.authorized_identity().identity().delete(ctx.sender);
+        ctx.db.authorized_identity().insert(AuthorizedIdentity {
+            identity: ctx.sender,
+            role: Role::Admin,
+            granted_by: ctx.sender,
+            granted_at: ctx.timestamp,
+        });
+    }
+}
+
+/// Gives `identity` the role `role`, replacing any role it held.
+#[spacetimedb::reducer]
+pub fn grant_role(ctx: &ReducerContext, identity: Identity, role: Role) -> Result<(), String> {
+    require_role(ctx, Role::Admin)?;
+    if role != Role::Admin {
+        ensure_other_admin(ctx, identity)?;
+    }
+    ctx.db.authorized_identity().identity().delete(identity);
+    ctx.db.authorized_identity().insert(AuthorizedIdentity {
+        identity,
+        role,
+        granted_by: ctx.sender,
+        granted_at: ctx.timestamp,
+    });
+    sessions::sync_role(ctx, identity);
+    log::info!("{} granted {role:?} to {identity}", ctx.sender);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn revoke_role(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    require_role(ctx, Role::Admin)?;
+    ensure_other_admin(ctx, identity)?;
+    if !ctx.db.authorized_identity().identity().delete(identity) {
+        return Err(format!("{identity} holds no role"));
+    }
+    sessions::sync_role(ctx, identity);
+    log::info!("{} revoked the role of {identity}", ctx.sender);
+    Ok(())
+}
+
+/// Fails if `identity` is the only admin, so the module can't be left without one.
+fn ensure_other_admin(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    let other_admin = ctx
+        .db
+        .authorized_identity()
+        .iter()
+        .any(|authorized| authorized.role == Role::Admin && authorized.identity != identity);
+    if other_admin {
+        Ok(())
+    } else {
+        Err(format!("{identity} is the only admin"))
+    }
+}
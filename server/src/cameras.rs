@@ -0,0 +1,375 @@
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table, Timestamp, table};
+
+use crate::auth::{self, Role};
+use crate::consensus;
+use crate::error::VoxelError;
+use crate::sessions;
+use crate::{Quaternion, Vector3, VoxelGrid};
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq, Debug)]
+pub enum CameraWeight {
+    /// Weighted by how well the camera resolves voxels at the grid's center, from its focal
+    /// length and distance, relative to the online camera covering the grid that resolves them
+    /// best. That camera's hits count fully.
+    Auto,
+    /// A fixed factor applied to every hit.
+    Manual(f32),
+}
+
+/// A camera client, keyed by the identity it connects with. Holds the intrinsics and extrinsics
+/// the client raymarches with, so hits can be attributed to the camera that produced them.
+#[table(name = camera, public)]
+pub struct Camera {
+    #[primary_key]
+    pub identity: Identity,
+    pub name: String,
+    /// Whether the camera's client is connected.
+    pub online: bool,
+    /// How much the camera's hits count for.
+    pub weight: CameraWeight,
+    /// Hits from a muted camera are counted but not applied.
+    pub muted: bool,
+    pub width: u32,
+    pub height: u32,
+    /// Horizontal field of view in degrees.
+    pub fov: f32,
+    /// Focal length in pixels.
+    pub focal_length: f32,
+    pub position: Vector3,
+    /// Rotation from camera axes to world axes.
+    pub orientation: Quaternion,
+    pub registered_at: Timestamp,
+    pub pose_updated_at: Timestamp,
+    pub frames_received: u64,
+    /// Hits and misses received.
+    pub hits_received: u64,
+    /// Hits and misses that were skipped as invalid, included in `hits_received`.
+    pub hits_rejected: u64,
+    /// Why the most recently rejected hit was rejected.
+    pub last_rejection: Option<String>,
+    pub last_frame_at: Option<Timestamp>,
+    /// Microseconds to add to the camera's clock to get server time, as the camera last reported.
+    pub clock_offset_micros: i64,
+    /// How fast the camera's clock drifts from the server's, in parts per million.
+    pub clock_drift_ppm: f64,
+    /// Round-trip time of the pings the offset was estimated from, in microseconds.
+    pub round_trip_micros: i64,
+    pub clock_reported_at: Option<Timestamp>,
+}
+
+#[spacetimedb::reducer]
+pub fn register_camera(
+    ctx: &ReducerContext,
+    name: String,
+    width: u32,
+    height: u32,
+    fov: f32,
+    focal_length: f32,
+    position: Vector3,
+    orientation: Quaternion,
+) -> Result<(), String> {
+    auth::require_role(ctx, Role::Camera)?;
+    if width == 0 || height == 0 {
+        return Err(format!(
+            "camera resolution must be non-zero, got {width}x{height}"
+        ));
+    }
+    if !(fov.is_finite() && fov > 0.0 && focal_length.is_finite() && focal_length > 0.0) {
+        return Err("camera field of view and focal length must be positive".to_string());
+    }
+    let (position, orientation) = validate_pose(position, orientation)?;
+
+    let cameras = ctx.db.camera();
+    match cameras.identity().find(ctx.sender) {
+        Some(camera) => {
+            cameras.identity().update(Camera {
+                name,
+                online: true,
+                width,
+                height,
+                fov,
+                focal_length,
+                position,
+                orientation,
+                pose_updated_at: ctx.timestamp,
+                ..camera
+            });
+        }
+        None => {
+            log::info!("registered camera {name} ({})", ctx.sender);
+            cameras.insert(Camera {
+                identity: ctx.sender,
+                name,
+                online: true,
+                weight: CameraWeight::Auto,
+                muted: false,
+                width,
+                height,
+                fov,
+                focal_length,
+                position,
+                orientation,
+                registered_at: ctx.timestamp,
+                pose_updated_at: ctx.timestamp,
+                frames_received: 0,
+                hits_received: 0,
+                hits_rejected: 0,
+                last_rejection: None,
+                last_frame_at: None,
+                clock_offset_micros: 0,
+                clock_drift_ppm: 0.0,
+                round_trip_micros: 0,
+                clock_reported_at: None,
+            });
+        }
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn update_camera_pose(
+    ctx: &ReducerContext,
+    position: Vector3,
+    orientation: Quaternion,
+) -> Result<(), String> {
+    auth::require_role(ctx, Role::Camera)?;
+    let (position, orientation) = validate_pose(position, orientation)?;
+    let camera = registered_camera(ctx)?;
+    sessions::touch(ctx);
+    ctx.db.camera().identity().update(Camera {
+        position,
+        orientation,
+        pose_updated_at: ctx.timestamp,
+        ..camera
+    });
+    Ok(())
+}
+
+/// Does nothing, so a client can read the server's time off the reducer event and pair it with
+/// `client_time`, which it gets back in the event's arguments.
+#[spacetimedb::reducer]
+pub fn ping(_ctx: &ReducerContext, _client_time: Timestamp) {}
+
+/// Stores the calling camera's clock estimate, which it applies to its capture timestamps itself.
+#[spacetimedb::reducer]
+pub fn report_clock_offset(
+    ctx: &ReducerContext,
+    offset_micros: i64,
+    drift_ppm: f64,
+    round_trip_micros: i64,
+) -> Result<(), String> {
+    auth::require_role(ctx, Role::Camera)?;
+    if !drift_ppm.is_finite() || round_trip_micros < 0 {
+        return Err("clock drift must be finite and round trip non-negative".to_string());
+    }
+    let camera = registered_camera(ctx)?;
+    sessions::touch(ctx);
+    ctx.db.camera().identity().update(Camera {
+        clock_offset_micros: offset_micros,
+        clock_drift_ppm: drift_ppm,
+        round_trip_micros,
+        clock_reported_at: Some(ctx.timestamp),
+        ..camera
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_camera_weight(
+    ctx: &ReducerContext,
+    identity: Identity,
+    weight: CameraWeight,
+) -> Result<(), String> {
+    auth::require_role(ctx, Role::Admin)?;
+    if let CameraWeight::Manual(factor) = weight
+        && !(factor.is_finite() && factor >= 0.0)
+    {
+        return Err(format!("camera weight must be non-negative, got {factor}"));
+    }
+    let camera = find_camera(ctx, identity)?;
+    ctx.db
+        .camera()
+        .identity()
+        .update(Camera { weight, ..camera });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_camera_muted(
+    ctx: &ReducerContext,
+    identity: Identity,
+    muted: bool,
+) -> Result<(), String> {
+    auth::require_role(ctx, Role::Admin)?;
+    let camera = find_camera(ctx, identity)?;
+    log::info!(
+        "{} camera {}",
+        if muted { "muted" } else { "unmuted" },
+        camera.name
+    );
+    ctx.db
+        .camera()
+        .identity()
+        .update(Camera { muted, ..camera });
+    Ok(())
+}
+
+fn find_camera(ctx: &ReducerContext, identity: Identity) -> Result<Camera, String> {
+    ctx.db
+        .camera()
+        .identity()
+        .find(identity)
+        .ok_or_else(|| format!("no camera registered by {identity}"))
+}
+
+/// Pixels a voxel at the grid's center spans in `camera`'s image.
+fn resolution(camera: &Camera, grid: &VoxelGrid) -> f32 {
+    let distance = camera.position.distance(grid.center()).max(grid.voxel_size);
+    camera.focal_length * grid.voxel_size / distance
+}
+
+/// Factor applied to hits from `camera` in `grid`, which `peers` cover as well.
+pub(crate) fn hit_weight(camera: &Camera, grid: &VoxelGrid, peers: &[Camera]) -> f32 {
+    match camera.weight {
+        CameraWeight::Manual(factor) => factor,
+        CameraWeight::Auto => {
+            // The further a camera is, the more voxels its rays smear over, so its hits count for
+            // less than those of the camera seeing the grid sharpest.
+            let own = resolution(camera, grid);
+            let best = peers
+                .iter()
+                .map(|peer| resolution(peer, grid))
+                .fold(own, f32::max);
+            if best > 0.0 { own / best } else { 1.0 }
+        }
+    }
+}
+
+fn validate_pose(
+    position: Vector3,
+    orientation: Quaternion,
+) -> Result<(Vector3, Quaternion), String> {
+    if !position.is_finite() {
+        return Err("camera position must be finite".to_string());
+    }
+    let orientation = orientation
+        .normalized()
+        .ok_or("camera orientation must be a non-zero quaternion")?;
+    Ok((position, orientation))
+}
+
+/// The camera registered by the caller of the current reducer.
+pub(crate) fn registered_camera(ctx: &ReducerContext) -> Result<Camera, VoxelError> {
+    ctx.db
+        .camera()
+        .identity()
+        .find(ctx.sender)
+        .ok_or(VoxelError::UnregisteredCamera(ctx.sender))
+}
+
+/// Counts a frame of `observations` hits and misses against the calling camera.
+pub(crate) fn record_frame(
+    ctx: &ReducerContext,
+    observations: usize,
+) -> Result<Camera, VoxelError> {
+    let camera = registered_camera(ctx)?;
+    sessions::touch(ctx);
+    Ok(ctx.db.camera().identity().update(Camera {
+        frames_received: camera.frames_received + 1,
+        hits_received: camera.hits_received + observations as u64,
+        last_frame_at: Some(ctx.timestamp),
+        ..camera
+    }))
+}
+
+/// Counts `rejected` hits and misses from one frame against `camera`, the last of them rejected for `error`.
+pub(crate) fn record_rejections(
+    ctx: &ReducerContext,
+    camera: Camera,
+    rejected: usize,
+    error: &VoxelError,
+) {
+    ctx.db.camera().identity().update(Camera {
+        hits_rejected: camera.hits_rejected + rejected as u64,
+        last_rejection: Some(error.to_string()),
+        ..camera
+    });
+}
+
+/// Marks the camera of `identity`, if it has one, as connected or not. A camera going offline
+/// stops supporting the voxels it hit.
+pub(crate) fn set_online(ctx: &ReducerContext, identity: Identity, online: bool) {
+    let Some(camera) = ctx.db.camera().identity().find(identity) else {
+        return;
+    };
+    if !online {
+        consensus::drop_camera(ctx, identity);
+    }
+    ctx.db
+        .camera()
+        .identity()
+        .update(Camera { online, ..camera });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn camera(position: Vector3) -> Camera {
+        Camera {
+            identity: Identity::ZERO,
+            name: String::new(),
+            online: true,
+            weight: CameraWeight::Auto,
+            muted: false,
+            width: 640,
+            height: 480,
+            fov: 60.0,
+            focal_length: 554.0,
+            position,
+            orientation: Quaternion::IDENTITY,
+            registered_at: Timestamp::UNIX_EPOCH,
+            pose_updated_at: Timestamp::UNIX_EPOCH,
+            frames_received: 0,
+            hits_received: 0,
+            hits_rejected: 0,
+            last_rejection: None,
+            last_frame_at: None,
+            clock_offset_micros: 0,
+            clock_drift_ppm: 0.0,
+            round_trip_micros: 0,
+            clock_reported_at: None,
+        }
+    }
+
+    #[test]
+    fn auto_weight_falls_off_with_distance() {
+        let grid = VoxelGrid::at_origin(10, 10, 10, 0.1);
+        let center = grid.center();
+        let at = |dz: f32| {
+            camera(Vector3 {
+                z: center.z + dz,
+                ..center
+            })
+        };
+        let cameras = [at(-2.0), at(8.0)];
+        let (near, far) = (&cameras[0], &cameras[1]);
+
+        assert_eq!(hit_weight(near, &grid, &cameras), 1.0);
+        assert!((hit_weight(far, &grid, &cameras) - 0.25).abs() < 1e-6);
+        // Alone, a far camera is the best view of the grid there is.
+        assert_eq!(hit_weight(far, &grid, &[]), 1.0);
+    }
+
+    #[test]
+    fn manual_weight_ignores_distance() {
+        let grid = VoxelGrid::at_origin(10, 10, 10, 0.1);
+        let mut far = camera(Vector3 {
+            x: 100.0,
+            ..grid.center()
+        });
+        far.weight = CameraWeight::Manual(0.5);
+        let near = camera(grid.center());
+        assert_eq!(hit_weight(&far, &grid, &[near]), 0.5);
+    }
+}
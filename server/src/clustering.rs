@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+
+use spacetimedb::{ReducerContext, SpacetimeType, Table, Timestamp, table};
+
+use crate::{Vector3, Voxel, VoxelGrid};
+
+/// Which voxels count as neighbours when grouping detections into objects.
+#[derive(SpacetimeType, Clone, Copy, PartialEq, Debug)]
+pub enum Connectivity {
+    /// Voxels sharing a face.
+    Face,
+    /// Voxels sharing a face, an edge or a corner.
+    Vertex,
+}
+impl Connectivity {
+    fn offsets(self) -> Vec<[i32; 3]> {
+        let mut offsets = Vec::new();
+        for dz in -1..=1 {
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    let steps = [dx, dy, dz].iter().filter(|&&d| d != 0).count();
+                    let connected = match self {
+                        Connectivity::Face => steps == 1,
+                        Connectivity::Vertex => steps > 0,
+                    };
+                    if connected {
+                        offsets.push([dx, dy, dz]);
+                    }
+                }
+            }
+        }
+        offsets
+    }
+}
+
+/// A connected group of detected voxels from the latest detection pass. Each pass replaces the
+/// grid's previous objects.
+#[table(name = object, public)]
+pub struct Object {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub grid_id: u32,
+    /// Value-weighted centroid in world coordinates.
+    pub centroid: Vector3,
+    /// Inclusive voxel bounds of the object along the grid's axes.
+    pub min: Voxel,
+    pub max: Voxel,
+    pub voxel_count: u32,
+    /// Sum of the object's voxel values.
+    pub energy: f32,
+    pub detected_at: Timestamp,
+}
+
+/// A connected component of voxels, in grid coordinates.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Cluster {
+    /// Value-weighted centroid in voxel units, see [`VoxelGrid::to_world`].
+    pub centroid: Vector3,
+    pub min: Voxel,
+    pub max: Voxel,
+    pub voxel_count: u32,
+    pub energy: f32,
+}
+
+/// Groups `voxels`, given as linear index and value, into connected components of `grid`.
+/// Components are returned in the order of their first voxel in `voxels`.
+pub fn label_components(
+    grid: &VoxelGrid,
+    voxels: &[(u64, f32)],
+    connectivity: Connectivity,
+) -> Vec<Cluster> {
+    let slots: HashMap<u64, usize> = voxels
+        .iter()
+        .enumerate()
+        .map(|(slot, &(idx, _))| (idx, slot))
+        .collect();
+    let offsets = connectivity.offsets();
+    let mut visited = vec![false; voxels.len()];
+    let mut stack = Vec::new();
+    let mut clusters = Vec::new();
+
+    for start in 0..voxels.len() {
+        if visited[start] {
+            continue;
+        }
+        visited[start] = true;
+        stack.push(start);
+
+        let first = grid.voxel(voxels[start].0);
+        let (mut min, mut max) = (first, first);
+        let mut weighted = [0.0f64; 3];
+        let mut energy = 0.0f64;
+        let mut voxel_count = 0;
+        while let Some(slot) = stack.pop() {
+            let (idx, value) = voxels[slot];
+            let voxel = grid.voxel(idx);
+            min = Voxel {
+                x: min.x.min(voxel.x),
+                y: min.y.min(voxel.y),
+                z: min.z.min(voxel.z),
+            };
+            max = Voxel {
+                x: max.x.max(voxel.x),
+                y: max.y.max(voxel.y),
+                z: max.z.max(voxel.z),
+            };
+            let value = value as f64;
+            weighted[0] += (voxel.x as f64 + 0.5) * value;
+            weighted[1] += (voxel.y as f64 + 0.5) * value;
+            weighted[2] += (voxel.z as f64 + 0.5) * value;
+            energy += value;
+            voxel_count += 1;
+
+            for offset in &offsets {
+                let Some(neighbour) = neighbour(grid, &voxel, offset) else {
+                    continue;
+                };
+                if let Some(&next) = slots.get(&(neighbour.idx(grid)))
+                    && !visited[next]
+                {
+                    visited[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+
+        let centroid = if energy > 0.0 {
+            Vector3 {
+                x: (weighted[0] / energy) as f32,
+                y: (weighted[1] / energy) as f32,
+                z: (weighted[2] / energy) as f32,
+            }
+        } else {
+            // No weights to go by, so use the middle of the bounds.
+            Vector3 {
+                x: (min.x + max.x + 1) as f32 * 0.5,
+                y: (min.y + max.y + 1) as f32 * 0.5,
+                z: (min.z + max.z + 1) as f32 * 0.5,
+            }
+        };
+        clusters.push(Cluster {
+            centroid,
+            min,
+            max,
+            voxel_count,
+            energy: energy as f32,
+        });
+    }
+    clusters
+}
+
+/// The voxel at `offset` from `voxel`, if it lies inside `grid`.
+fn neighbour(grid: &VoxelGrid, voxel: &Voxel, offset: &[i32; 3]) -> Option<Voxel> {
+    let step = |coord: u32, delta: i32, len: u32| {
+        coord.checked_add_signed(delta).filter(|&coord| coord < len)
+    };
+    Some(Voxel {
+        x: step(voxel.x, offset[0], grid.nx)?,
+        y: step(voxel.y, offset[1], grid.ny)?,
+        z: step(voxel.z, offset[2], grid.nz)?,
+    })
+}
+
+/// Replaces `grid`'s objects with the components of `detected`, dropping any smaller than the
+/// grid's `min_object_voxels`. Returns the new objects.
+pub(crate) fn update_objects(
+    ctx: &ReducerContext,
+    grid: &VoxelGrid,
+    detected: &[(u64, f32)],
+) -> Vec<Object> {
+    clear_objects(ctx, grid.id);
+    let mut objects = Vec::new();
+    for cluster in label_components(grid, detected, grid.detection.connectivity) {
+        if cluster.voxel_count < grid.detection.min_object_voxels {
+            continue;
+        }
+        objects.push(ctx.db.object().insert(Object {
+            id: 0,
+            grid_id: grid.id,
+            centroid: grid.to_world(cluster.centroid),
+            min: cluster.min,
+            max: cluster.max,
+            voxel_count: cluster.voxel_count,
+            energy: cluster.energy,
+            detected_at: ctx.timestamp,
+        }));
+    }
+    objects
+}
+
+pub(crate) fn clear_objects(ctx: &ReducerContext, grid_id: u32) {
+    let ids: Vec<u64> = ctx
+        .db
+        .object()
+        .grid_id()
+        .filter(grid_id)
+        .map(|row| row.id)
+        .collect();
+    for id in ids {
+        ctx.db.object().id().delete(id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hot(grid: &VoxelGrid, coords: &[(u32, u32, u32)]) -> Vec<(u64, f32)> {
+        coords
+            .iter()
+            .map(|&(x, y, z)| (Voxel { x, y, z }.idx(grid), 1.0))
+            .collect()
+    }
+
+    #[test]
+    fn separate_blobs_are_separate_objects() {
+        let grid = VoxelGrid::at_origin(10, 10, 10, 1.0);
+        let voxels = hot(
+            &grid,
+            &[(1, 1, 1), (2, 1, 1), (1, 2, 1), (7, 7, 7), (7, 7, 8)],
+        );
+        let clusters = label_components(&grid, &voxels, Connectivity::Vertex);
+        let counts: Vec<u32> = clusters.iter().map(|c| c.voxel_count).collect();
+        assert_eq!(counts, vec![3, 2]);
+        assert_eq!(clusters[1].min, Voxel { x: 7, y: 7, z: 7 });
+        assert_eq!(clusters[1].max, Voxel { x: 7, y: 7, z: 8 });
+        assert_eq!(clusters[1].energy, 2.0);
+    }
+
+    #[test]
+    fn diagonal_neighbours_depend_on_connectivity() {
+        let grid = VoxelGrid::at_origin(5, 5, 5, 1.0);
+        let voxels = hot(&grid, &[(1, 1, 1), (2, 2, 2), (3, 3, 2)]);
+        assert_eq!(
+            label_components(&grid, &voxels, Connectivity::Face).len(),
+            3
+        );
+        let clusters = label_components(&grid, &voxels, Connectivity::Vertex);
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].voxel_count, 3);
+    }
+
+    #[test]
+    fn neighbours_do_not_wrap_around_grid_edges() {
+        // (3, 0, 0) and (0, 1, 0) have adjacent linear indices but are on opposite faces.
+        let grid = VoxelGrid::at_origin(4, 4, 4, 1.0);
+        let voxels = hot(&grid, &[(3, 0, 0), (0, 1, 0)]);
+        assert_eq!(voxels[1].0, voxels[0].0 + 1);
+        assert_eq!(
+            label_components(&grid, &voxels, Connectivity::Vertex).len(),
+            2
+        );
+    }
+
+    #[test]
+    fn centroid_is_value_weighted() {
+        let grid = VoxelGrid::at_origin(4, 4, 4, 1.0);
+        let voxels = vec![
+            (Voxel { x: 0, y: 2, z: 1 }.idx(&grid), 1.0),
+            (Voxel { x: 1, y: 2, z: 1 }.idx(&grid), 3.0),
+        ];
+        let clusters = label_components(&grid, &voxels, Connectivity::Face);
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(
+            clusters[0].centroid,
+            Vector3 {
+                x: 1.25,
+                y: 2.5,
+                z: 1.5
+            }
+        );
+        assert_eq!(clusters[0].energy, 4.0);
+    }
+
+    #[test]
+    fn no_voxels_no_objects() {
+        let grid = VoxelGrid::at_origin(4, 4, 4, 1.0);
+        assert!(label_components(&grid, &[], Connectivity::Face).is_empty());
+    }
+}
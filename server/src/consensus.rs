@@ -0,0 +1,239 @@
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table, Timestamp, table};
+
+use crate::auth::{self, Role};
+use crate::{VoxelGrid, voxel_grid};
+
+/// A single camera only constrains a voxel to lie somewhere along its ray, so voxels are trusted
+/// once enough distinct cameras have hit them recently.
+#[derive(SpacetimeType, Clone, Copy, PartialEq, Debug)]
+pub struct ConsensusConfig {
+    /// Distinct cameras that must have hit a voxel within the window for it to count fully. At
+    /// most 1, voxels always count fully, even once their hits fall out of the window.
+    pub min_cameras: u32,
+    /// Milliseconds a camera's hit keeps supporting a voxel.
+    pub window_ms: u64,
+    /// Factor, between 0 and 1, applied to the value of voxels with too few cameras.
+    pub unsupported_weight: f32,
+}
+impl Default for ConsensusConfig {
+    fn default() -> Self {
+        // One camera suffices, which disables gating, so a single-camera setup behaves as it did
+        // before consensus.
+        Self {
+            min_cameras: 1,
+            window_ms: 1000,
+            unsupported_weight: 0.0,
+        }
+    }
+}
+
+impl ConsensusConfig {
+    /// Whether voxels need more than the one camera that hit them to count fully. Contributions
+    /// are only recorded for grids that gate.
+    pub(crate) fn gates(&self) -> bool {
+        self.min_cameras > 1
+    }
+}
+
+/// When a camera last hit a voxel.
+#[table(
+    name = voxel_contribution,
+    index(name = grid_voxel_camera, btree(columns = [grid_id, voxel, camera]))
+)]
+pub struct VoxelContribution {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub grid_id: u32,
+    /// Linear index of the voxel, see [`crate::Voxel::idx`].
+    pub voxel: u64,
+    #[index(btree)]
+    pub camera: Identity,
+    /// Capture time of the camera's latest frame that hit the voxel.
+    pub last_hit: Timestamp,
+}
+
+fn in_window(config: &ConsensusConfig, last_hit: Timestamp, now: Timestamp) -> bool {
+    let age_micros = now.to_micros_since_unix_epoch() - last_hit.to_micros_since_unix_epoch();
+    age_micros <= config.window_ms as i64 * 1000
+}
+
+/// Records that the calling camera hit `voxel` in a frame captured at `at` and returns how many
+/// distinct cameras, including it, have hit the voxel within the grid's window. Drops
+/// contributions that fell out of it. Grids that don't gate record nothing and count only the
+/// calling camera, sparing every hit an extra scan and write.
+pub(crate) fn record_contribution(
+    ctx: &ReducerContext,
+    grid: &VoxelGrid,
+    voxel: u64,
+    at: Timestamp,
+) -> u32 {
+    if !grid.consensus.gates() {
+        return 1;
+    }
+    let contributions = ctx.db.voxel_contribution();
+    let mut cameras = 1;
+    let mut own = None;
+    let mut stale = Vec::new();
+    for row in contributions.grid_voxel_camera().filter((grid.id, voxel)) {
+        if row.camera == ctx.sender {
+            own = Some(row);
+        } else if in_window(&grid.consensus, row.last_hit, ctx.timestamp) {
+            cameras += 1;
+        } else {
+            stale.push(row.id);
+        }
+    }
+    for id in stale {
+        contributions.id().delete(id);
+    }
+    match own {
+        Some(row) => {
+            contributions.id().update(VoxelContribution {
+                last_hit: row.last_hit.max(at),
+                ..row
+            });
+        }
+        None => {
+            contributions.insert(VoxelContribution {
+                id: 0,
+                grid_id: grid.id,
+                voxel,
+                camera: ctx.sender,
+                last_hit: at,
+            });
+        }
+    }
+    cameras
+}
+
+/// Distinct cameras that have hit `voxel` within the grid's window as of now.
+pub(crate) fn supporting_cameras(ctx: &ReducerContext, grid: &VoxelGrid, voxel: u64) -> u32 {
+    ctx.db
+        .voxel_contribution()
+        .grid_voxel_camera()
+        .filter((grid.id, voxel))
+        .filter(|row| in_window(&grid.consensus, row.last_hit, ctx.timestamp))
+        .count() as u32
+}
+
+/// Factor applied to a voxel's value when `cameras` distinct cameras support it.
+pub(crate) fn weight(grid: &VoxelGrid, cameras: u32) -> f32 {
+    // A voxel that still has a value was hit by at least one camera, so requiring one would only
+    // drop voxels whose hits outlived the window.
+    if !grid.consensus.gates() || cameras >= grid.consensus.min_cameras {
+        1.0
+    } else {
+        grid.consensus.unsupported_weight
+    }
+}
+
+/// Removes the grid's contributions that fell out of its window and returns how many there were.
+pub(crate) fn prune_contributions(ctx: &ReducerContext, grid: &VoxelGrid) -> u64 {
+    let stale: Vec<u64> = ctx
+        .db
+        .voxel_contribution()
+        .grid_voxel_camera()
+        .filter(grid.id)
+        .filter(|row| !in_window(&grid.consensus, row.last_hit, ctx.timestamp))
+        .map(|row| row.id)
+        .collect();
+    for &id in &stale {
+        ctx.db.voxel_contribution().id().delete(id);
+    }
+    stale.len() as u64
+}
+
+/// Removes every contribution of `camera`, in all grids.
+pub(crate) fn drop_camera(ctx: &ReducerContext, camera: Identity) {
+    let ids: Vec<u64> = ctx
+        .db
+        .voxel_contribution()
+        .camera()
+        .filter(camera)
+        .map(|row| row.id)
+        .collect();
+    for id in ids {
+        ctx.db.voxel_contribution().id().delete(id);
+    }
+}
+
+pub(crate) fn clear_contributions(ctx: &ReducerContext, grid_id: u32) {
+    let ids: Vec<u64> = ctx
+        .db
+        .voxel_contribution()
+        .grid_voxel_camera()
+        .filter(grid_id)
+        .map(|row| row.id)
+        .collect();
+    for id in ids {
+        ctx.db.voxel_contribution().id().delete(id);
+    }
+}
+
+/// Removes every camera's contribution to `voxel`.
+pub(crate) fn clear_voxel(ctx: &ReducerContext, grid_id: u32, voxel: u64) {
+    let ids: Vec<u64> = ctx
+        .db
+        .voxel_contribution()
+        .grid_voxel_camera()
+        .filter((grid_id, voxel))
+        .map(|row| row.id)
+        .collect();
+    for id in ids {
+        ctx.db.voxel_contribution().id().delete(id);
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn configure_consensus(
+    ctx: &ReducerContext,
+    grid_id: u32,
+    config: ConsensusConfig,
+) -> Result<(), String> {
+    auth::require_role(ctx, Role::Admin)?;
+    if config.window_ms == 0 {
+        return Err("consensus window must be non-zero".to_string());
+    }
+    if !(0.0..=1.0).contains(&config.unsupported_weight) {
+        return Err(format!(
+            "unsupported weight must be between 0 and 1, got {}",
+            config.unsupported_weight
+        ));
+    }
+    let mut grid = ctx
+        .db
+        .voxel_grid()
+        .id()
+        .find(grid_id)
+        .ok_or_else(|| format!("no voxel grid with id {grid_id}"))?;
+    grid.consensus = config;
+    ctx.db.voxel_grid().id().update(grid);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stale_voxels_count_fully_by_default() {
+        let grid = VoxelGrid::at_origin(4, 4, 4, 1.0);
+        // No camera hit the voxel within the window, yet its value counts fully.
+        assert_eq!(weight(&grid, 0), 1.0);
+    }
+
+    #[test]
+    fn too_few_cameras_are_weighted_down() {
+        let mut grid = VoxelGrid::at_origin(4, 4, 4, 1.0);
+        grid.consensus = ConsensusConfig {
+            min_cameras: 2,
+            unsupported_weight: 0.25,
+            ..ConsensusConfig::default()
+        };
+        assert_eq!(weight(&grid, 0), 0.25);
+        assert_eq!(weight(&grid, 1), 0.25);
+        assert_eq!(weight(&grid, 2), 1.0);
+        assert_eq!(weight(&grid, 3), 1.0);
+    }
+}
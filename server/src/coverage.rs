@@ -0,0 +1,102 @@
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp, table};
+
+use crate::auth::{self, Role};
+use crate::cameras::{self, camera};
+use crate::error::VoxelError;
+use crate::{VoxelGrid, voxel_grid};
+
+/// A grid a camera sees and sends hits for. A camera may cover any number of grids and only
+/// contributes to those it covers.
+#[table(
+    name = camera_coverage,
+    public,
+    index(name = camera_grid, btree(columns = [camera, grid_id]))
+)]
+pub struct CameraCoverage {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub camera: Identity,
+    #[index(btree)]
+    pub grid_id: u32,
+    pub since: Timestamp,
+}
+
+fn covers(ctx: &ReducerContext, camera: Identity, grid_id: u32) -> Option<CameraCoverage> {
+    ctx.db
+        .camera_coverage()
+        .camera_grid()
+        .filter((camera, grid_id))
+        .next()
+}
+
+/// The grid `grid_id` if the calling camera covers it.
+pub(crate) fn covered_grid(ctx: &ReducerContext, grid_id: u32) -> Result<VoxelGrid, VoxelError> {
+    let grid = ctx
+        .db
+        .voxel_grid()
+        .id()
+        .find(grid_id)
+        .ok_or(VoxelError::UnknownGrid(grid_id))?;
+    match covers(ctx, ctx.sender, grid_id) {
+        Some(_) => Ok(grid),
+        None => Err(VoxelError::NotCovered {
+            camera: ctx.sender,
+            grid_id,
+        }),
+    }
+}
+
+/// The online cameras covering `grid_id`.
+pub(crate) fn covering_cameras(ctx: &ReducerContext, grid_id: u32) -> Vec<cameras::Camera> {
+    ctx.db
+        .camera_coverage()
+        .grid_id()
+        .filter(grid_id)
+        .filter_map(|coverage| ctx.db.camera().identity().find(coverage.camera))
+        .filter(|camera| camera.online)
+        .collect()
+}
+
+/// Removes every camera's coverage of `grid_id`.
+pub(crate) fn clear_coverage(ctx: &ReducerContext, grid_id: u32) {
+    let ids: Vec<u64> = ctx
+        .db
+        .camera_coverage()
+        .grid_id()
+        .filter(grid_id)
+        .map(|row| row.id)
+        .collect();
+    for id in ids {
+        ctx.db.camera_coverage().id().delete(id);
+    }
+}
+
+/// Declares that the calling camera covers grid `grid_id`. Covering a grid twice is harmless.
+#[spacetimedb::reducer]
+pub fn cover_grid(ctx: &ReducerContext, grid_id: u32) -> Result<(), String> {
+    auth::require_role(ctx, Role::Camera)?;
+    let camera = cameras::registered_camera(ctx)?;
+    if ctx.db.voxel_grid().id().find(grid_id).is_none() {
+        return Err(VoxelError::UnknownGrid(grid_id).into());
+    }
+    if covers(ctx, ctx.sender, grid_id).is_none() {
+        ctx.db.camera_coverage().insert(CameraCoverage {
+            id: 0,
+            camera: ctx.sender,
+            grid_id,
+            since: ctx.timestamp,
+        });
+        log::info!("camera {} covers grid {grid_id}", camera.name);
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn uncover_grid(ctx: &ReducerContext, grid_id: u32) -> Result<(), String> {
+    auth::require_role(ctx, Role::Camera)?;
+    let coverage = covers(ctx, ctx.sender, grid_id)
+        .ok_or_else(|| format!("{} does not cover grid {grid_id}", ctx.sender))?;
+    ctx.db.camera_coverage().id().delete(coverage.id);
+    Ok(())
+}
@@ -0,0 +1,260 @@
+use std::time::Duration;
+
+use spacetimedb::{ReducerContext, ScheduleAt, SpacetimeType, Table, Timestamp, table};
+
+use crate::auth::{self, Role};
+use crate::clustering::{self, Connectivity};
+use crate::consensus;
+use crate::tracking;
+use crate::{Vector3, VoxelGrid, decay, voxel_grid, voxel_value};
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq, Debug)]
+pub enum DetectionThreshold {
+    /// Voxels whose value is at least this.
+    Absolute(f32),
+    /// Voxels in the top `100 - p` percent of the grid's non-zero voxel values, for `p` from 0 to
+    /// 100, and at least the largest. Voxels tied with the smallest of them are detected too.
+    Percentile(f32),
+}
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq, Debug)]
+pub struct DetectionConfig {
+    pub threshold: DetectionThreshold,
+    /// Milliseconds between detection passes over the grid.
+    pub interval_ms: u64,
+    /// How detected voxels are grouped into objects.
+    pub connectivity: Connectivity,
+    /// Components with fewer voxels than this are not reported as objects.
+    pub min_object_voxels: u32,
+}
+impl Default for DetectionConfig {
+    fn default() -> Self {
+        Self {
+            threshold: DetectionThreshold::Percentile(99.0),
+            interval_ms: 200,
+            connectivity: Connectivity::Vertex,
+            min_object_voxels: 1,
+        }
+    }
+}
+
+/// A voxel the latest detection pass considered part of a moving object. Each pass replaces the
+/// grid's previous detections.
+#[table(name = detection, public)]
+pub struct Detection {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub grid_id: u32,
+    /// Linear index of the voxel, see [`crate::Voxel::idx`].
+    pub voxel: u64,
+    /// World position of the voxel's center.
+    pub position: Vector3,
+    /// The voxel's value decayed to `detected_at` and weighted by its camera support.
+    pub value: f32,
+    pub detected_at: Timestamp,
+}
+
+#[table(name = detection_schedule, scheduled(run_detection))]
+pub struct DetectionSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+    #[unique]
+    pub grid_id: u32,
+}
+
+/// Starts, or restarts with the grid's current interval, the detection passes over `grid`.
+pub(crate) fn schedule_detection(ctx: &ReducerContext, grid: &VoxelGrid) {
+    ctx.db.detection_schedule().grid_id().delete(grid.id);
+    ctx.db.detection_schedule().insert(DetectionSchedule {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(
+            Duration::from_millis(grid.detection.interval_ms).into(),
+        ),
+        grid_id: grid.id,
+    });
+}
+
+#[spacetimedb::reducer]
+pub fn configure_detection(
+    ctx: &ReducerContext,
+    grid_id: u32,
+    config: DetectionConfig,
+) -> Result<(), String> {
+    auth::require_role(ctx, Role::Admin)?;
+    match config.threshold {
+        DetectionThreshold::Absolute(value) if !(value.is_finite() && value >= 0.0) => {
+            return Err(format!(
+                "absolute threshold must be non-negative, got {value}"
+            ));
+        }
+        DetectionThreshold::Percentile(p) if !(0.0..=100.0).contains(&p) => {
+            return Err(format!("percentile must be between 0 and 100, got {p}"));
+        }
+        _ => {}
+    }
+    if config.interval_ms == 0 {
+        return Err("detection interval must be non-zero".to_string());
+    }
+    let mut grid = ctx
+        .db
+        .voxel_grid()
+        .id()
+        .find(grid_id)
+        .ok_or_else(|| format!("no voxel grid with id {grid_id}"))?;
+    let interval_changed = grid.detection.interval_ms != config.interval_ms;
+    grid.detection = config;
+    let grid = ctx.db.voxel_grid().id().update(grid);
+    if interval_changed {
+        schedule_detection(ctx, &grid);
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn run_detection(ctx: &ReducerContext, schedule: DetectionSchedule) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("run_detection may only be invoked by the scheduler".to_string());
+    }
+    let Some(grid) = ctx.db.voxel_grid().id().find(schedule.grid_id) else {
+        ctx.db
+            .detection_schedule()
+            .scheduled_id()
+            .delete(schedule.scheduled_id);
+        return Ok(());
+    };
+
+    let values: Vec<(u64, f32)> = ctx
+        .db
+        .voxel_value()
+        .grid_voxel()
+        .filter(grid.id)
+        .map(|row| {
+            let value = decay(row.value, row.updated_at, ctx.timestamp, grid.half_life);
+            // Grids that don't gate record no contributions to count.
+            let cameras = if grid.consensus.gates() {
+                consensus::supporting_cameras(ctx, &grid, row.voxel)
+            } else {
+                0
+            };
+            (row.voxel, value * consensus::weight(&grid, cameras))
+        })
+        // Voxels without enough camera support may be weighted out entirely.
+        .filter(|&(_, value)| value > 0.0)
+        .collect();
+
+    clear_detections(ctx, grid.id);
+    let mut all_values: Vec<f32> = values.iter().map(|&(_, value)| value).collect();
+    let detected: Vec<(u64, f32)> = match threshold_value(grid.detection.threshold, &mut all_values)
+    {
+        Some(threshold) => values
+            .into_iter()
+            .filter(|&(_, value)| value >= threshold)
+            .collect(),
+        None => Vec::new(),
+    };
+    for &(voxel, value) in &detected {
+        ctx.db.detection().insert(Detection {
+            id: 0,
+            grid_id: grid.id,
+            voxel,
+            position: grid.voxel_center(&grid.voxel(voxel)),
+            value,
+            detected_at: ctx.timestamp,
+        });
+    }
+    let objects = clustering::update_objects(ctx, &grid, &detected);
+    tracking::update_tracks(ctx, &grid, &objects);
+    Ok(())
+}
+
+pub(crate) fn clear_detections(ctx: &ReducerContext, grid_id: u32) {
+    let ids: Vec<u64> = ctx
+        .db
+        .detection()
+        .grid_id()
+        .filter(grid_id)
+        .map(|row| row.id)
+        .collect();
+    for id in ids {
+        ctx.db.detection().id().delete(id);
+    }
+}
+
+/// The value a voxel must reach to be detected, or `None` if no voxel can be. Reorders `values`.
+pub fn threshold_value(threshold: DetectionThreshold, values: &mut [f32]) -> Option<f32> {
+    match threshold {
+        DetectionThreshold::Absolute(value) => Some(value),
+        DetectionThreshold::Percentile(_) if values.is_empty() => None,
+        DetectionThreshold::Percentile(p) => {
+            // The smallest value with p% of values below it, so 99 keeps the top 1%.
+            let below = (p * values.len() as f32 / 100.0).floor() as usize;
+            let idx = below.min(values.len() - 1);
+            let (_, value, _) = values.select_nth_unstable_by(idx, f32::total_cmp);
+            Some(*value)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// How many of `values` a pass with `threshold` detects.
+    fn detected(threshold: DetectionThreshold, values: &[f32]) -> usize {
+        let mut sorted = values.to_vec();
+        match threshold_value(threshold, &mut sorted) {
+            Some(threshold) => values.iter().filter(|&&value| value >= threshold).count(),
+            None => 0,
+        }
+    }
+
+    fn ramp(n: usize) -> Vec<f32> {
+        (1..=n).rev().map(|i| i as f32).collect()
+    }
+
+    #[test]
+    fn percentile_99_keeps_the_top_percent() {
+        let values = ramp(100);
+        assert_eq!(detected(DetectionThreshold::Percentile(99.0), &values), 1);
+        assert_eq!(detected(DetectionThreshold::Percentile(90.0), &values), 10);
+        assert_eq!(
+            detected(DetectionThreshold::Percentile(99.0), &ramp(1000)),
+            10
+        );
+    }
+
+    #[test]
+    fn percentile_extremes() {
+        let values = ramp(100);
+        assert_eq!(detected(DetectionThreshold::Percentile(0.0), &values), 100);
+        assert_eq!(detected(DetectionThreshold::Percentile(100.0), &values), 1);
+        assert_eq!(
+            threshold_value(DetectionThreshold::Percentile(100.0), &mut ramp(100)),
+            Some(100.0)
+        );
+    }
+
+    #[test]
+    fn empty_grids_detect_nothing() {
+        assert_eq!(
+            threshold_value(DetectionThreshold::Percentile(99.0), &mut []),
+            None
+        );
+        assert_eq!(
+            threshold_value(DetectionThreshold::Absolute(0.5), &mut []),
+            Some(0.5)
+        );
+    }
+
+    #[test]
+    fn ties_with_the_threshold_are_detected() {
+        let values = [1.0, 2.0, 2.0, 2.0];
+        assert_eq!(detected(DetectionThreshold::Percentile(50.0), &values), 3);
+        assert_eq!(detected(DetectionThreshold::Percentile(100.0), &values), 3);
+        assert_eq!(detected(DetectionThreshold::Percentile(0.0), &values), 4);
+    }
+}
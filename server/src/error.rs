@@ -0,0 +1,74 @@
+use std::fmt;
+
+use spacetimedb::Identity;
+
+use crate::Voxel;
+
+/// Why a voxel update, or one hit of it, was rejected.
+#[derive(Debug, Clone, PartialEq)]
+pub enum VoxelError {
+    /// The caller has not registered a camera.
+    UnregisteredCamera(Identity),
+    /// There is no grid with this id.
+    UnknownGrid(u32),
+    /// The calling camera has not declared that it covers the grid.
+    NotCovered {
+        camera: Identity,
+        grid_id: u32,
+    },
+    /// The voxel lies outside the grid.
+    OutOfBounds {
+        voxel: Voxel,
+        grid_id: u32,
+        dims: (u32, u32, u32),
+    },
+    /// The hit's value is NaN or infinite.
+    NonFiniteValue(f32),
+    /// The hit's value is below zero.
+    NegativeValue(f32),
+    /// The frame was captured longer ago than the grid accepts.
+    StaleFrame {
+        grid_id: u32,
+        age_ms: i64,
+        horizon_ms: u64,
+    },
+}
+
+impl fmt::Display for VoxelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VoxelError::UnregisteredCamera(identity) => {
+                write!(f, "{identity} has not registered a camera")
+            }
+            VoxelError::UnknownGrid(grid_id) => write!(f, "no voxel grid with id {grid_id}"),
+            VoxelError::NotCovered { camera, grid_id } => {
+                write!(f, "camera {camera} does not cover grid {grid_id}")
+            }
+            VoxelError::OutOfBounds {
+                voxel,
+                grid_id,
+                dims: (nx, ny, nz),
+            } => write!(
+                f,
+                "voxel ({}, {}, {}) is outside grid {grid_id} of {nx}x{ny}x{nz} voxels",
+                voxel.x, voxel.y, voxel.z
+            ),
+            VoxelError::NonFiniteValue(value) => write!(f, "hit value {value} is not finite"),
+            VoxelError::NegativeValue(value) => write!(f, "hit value {value} is negative"),
+            VoxelError::StaleFrame {
+                grid_id,
+                age_ms,
+                horizon_ms,
+            } => write!(
+                f,
+                "frame is {age_ms} ms old, grid {grid_id} only accepts frames up to {horizon_ms} ms old"
+            ),
+        }
+    }
+}
+
+impl From<VoxelError> for String {
+    fn from(error: VoxelError) -> Self {
+        error.to_string()
+    }
+}
@@ -1,57 +1,918 @@
-use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table, Timestamp, reducer, table};
+use std::time::Duration;
 
-#[derive(SpacetimeType)]
+use spacetimedb::{
+    Identity, ReducerContext, ScheduleAt, SpacetimeType, Table, Timestamp, reducer, table,
+};
+
+mod aggregation;
+mod auth;
+mod cameras;
+mod clustering;
+mod consensus;
+mod coverage;
+mod detector;
+mod error;
+mod sessions;
+mod tracking;
+
+use aggregation::AggregationMode;
+use auth::Role;
+use consensus::ConsensusConfig;
+use detector::DetectionConfig;
+use error::VoxelError;
+use tracking::TrackerConfig;
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq, Debug)]
 pub struct Voxel {
     x: u32,
     y: u32,
     z: u32,
 }
 impl Voxel {
-    pub fn idx(&self, grid_size: u32) -> usize {
-        (self.x + self.y * grid_size + self.z * grid_size * grid_size) as usize
+    /// Linear index of the voxel in `grid`, x varying fastest. Only meaningful for voxels inside
+    /// the grid, see [`Voxel::checked_idx`].
+    pub fn idx(&self, grid: &VoxelGrid) -> u64 {
+        let (nx, ny) = (grid.nx as u64, grid.ny as u64);
+        self.x as u64 + self.y as u64 * nx + self.z as u64 * nx * ny
+    }
+
+    /// Whether the voxel lies in the box from `min` to `max`, inclusive.
+    fn within(&self, min: &Voxel, max: &Voxel) -> bool {
+        (min.x..=max.x).contains(&self.x)
+            && (min.y..=max.y).contains(&self.y)
+            && (min.z..=max.z).contains(&self.z)
+    }
+
+    /// Linear index of the voxel in `grid`, or an error if it lies outside.
+    pub fn checked_idx(&self, grid: &VoxelGrid) -> Result<u64, VoxelError> {
+        if self.x < grid.nx && self.y < grid.ny && self.z < grid.nz {
+            Ok(self.idx(grid))
+        } else {
+            Err(VoxelError::OutOfBounds {
+                voxel: *self,
+                grid_id: grid.id,
+                dims: (grid.nx, grid.ny, grid.nz),
+            })
+        }
+    }
+}
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq, Debug)]
+pub struct Vector3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+impl Vector3 {
+    pub const ZERO: Self = Self {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    };
+
+    fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
+    fn distance(&self, other: Vector3) -> f32 {
+        let (dx, dy, dz) = (self.x - other.x, self.y - other.y, self.z - other.z);
+        (dx * dx + dy * dy + dz * dz).sqrt()
     }
 }
 
-const GRID_SIZE: usize = 100;
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub struct Quaternion {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+impl Quaternion {
+    pub const IDENTITY: Self = Self {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+        w: 1.0,
+    };
+
+    /// Returns the unit quaternion pointing the same way, if there is one.
+    fn normalized(&self) -> Option<Self> {
+        let len = (self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w).sqrt();
+        if !len.is_finite() || len < 1e-6 {
+            return None;
+        }
+        Some(Self {
+            x: self.x / len,
+            y: self.y / len,
+            z: self.z / len,
+            w: self.w / len,
+        })
+    }
+
+    /// Rotates `v` by this unit quaternion.
+    fn rotate(&self, v: Vector3) -> Vector3 {
+        let cross = |a: Vector3, b: Vector3| Vector3 {
+            x: a.y * b.z - a.z * b.y,
+            y: a.z * b.x - a.x * b.z,
+            z: a.x * b.y - a.y * b.x,
+        };
+        let axis = Vector3 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        };
+        let t = cross(axis, v);
+        let t = Vector3 {
+            x: 2.0 * t.x,
+            y: 2.0 * t.y,
+            z: 2.0 * t.z,
+        };
+        let u = cross(axis, t);
+        Vector3 {
+            x: v.x + self.w * t.x + u.x,
+            y: v.y + self.w * t.y + u.y,
+            z: v.z + self.w * t.z + u.z,
+        }
+    }
+}
+
+#[derive(SpacetimeType)]
+pub struct VoxelHit {
+    voxel: Voxel,
+    value: f32,
+}
+impl VoxelHit {
+    fn validate_value(&self) -> Result<(), VoxelError> {
+        if !self.value.is_finite() {
+            Err(VoxelError::NonFiniteValue(self.value))
+        } else if self.value < 0.0 {
+            Err(VoxelError::NegativeValue(self.value))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Name of the grid `init` creates when there is none.
+const DEFAULT_GRID_NAME: &str = "default";
+const DEFAULT_GRID_SIZE: u32 = 100;
+const DEFAULT_HALF_LIFE: f32 = 2.0;
+const DEFAULT_MAINTENANCE_INTERVAL_MS: u64 = 1000;
+const DEFAULT_FRAME_HORIZON_MS: u64 = 1000;
+/// Voxels whose value falls within this of zero are removed from `voxel_value`.
+const PRUNE_EPSILON: f32 = 1e-3;
+/// Most voxels a grid may have, so camera clients can count them in a `u32`.
+const MAX_GRID_VOXELS: u64 = u32::MAX as u64;
+/// A box of `nx * ny * nz` cubic voxels. This row is the single source of truth for the grid's
+/// geometry: cameras derive their voxel layout from it and hits are indexed against it.
 #[table(name = voxel_grid, public)]
 pub struct VoxelGrid {
     #[primary_key]
     #[auto_inc]
     pub id: u32,
+    /// Human-readable name, e.g. "lobby", that cameras look the grid up by.
+    #[unique]
+    pub name: String,
+    pub nx: u32,
+    pub ny: u32,
+    pub nz: u32,
+    /// World position of the grid's minimum corner, the outer corner of voxel (0, 0, 0).
+    pub origin: Vector3,
+    /// Edge length of a voxel in world units.
     pub voxel_size: f32,
-    pub grid: Vec<f32>,
+    /// Rotation from the grid's local axes to world axes, applied about `origin`.
+    pub orientation: Quaternion,
+    pub aggregation: AggregationMode,
+    /// Seconds it takes an untouched voxel's value to fall to half.
+    pub half_life: f32,
+    /// Milliseconds between maintenance passes that decay and prune the grid's values.
+    pub maintenance_interval_ms: u64,
+    /// Frames captured longer than this many milliseconds ago are not applied to the grid.
+    pub frame_horizon_ms: u64,
+    pub detection: DetectionConfig,
+    pub tracking: TrackerConfig,
+    pub consensus: ConsensusConfig,
+}
+impl VoxelGrid {
+    /// A grid with the given name and geometry and default settings. The id is assigned on insert.
+    pub fn new(
+        name: String,
+        nx: u32,
+        ny: u32,
+        nz: u32,
+        origin: Vector3,
+        voxel_size: f32,
+        orientation: Quaternion,
+    ) -> Self {
+        Self {
+            id: 0,
+            name,
+            nx,
+            ny,
+            nz,
+            origin,
+            voxel_size,
+            orientation,
+            aggregation: AggregationMode::Additive,
+            half_life: DEFAULT_HALF_LIFE,
+            maintenance_interval_ms: DEFAULT_MAINTENANCE_INTERVAL_MS,
+            frame_horizon_ms: DEFAULT_FRAME_HORIZON_MS,
+            detection: DetectionConfig::default(),
+            tracking: TrackerConfig::default(),
+            consensus: ConsensusConfig::default(),
+        }
+    }
+
+    /// An axis-aligned grid at the world origin with default settings, for tests.
+    #[cfg(test)]
+    pub(crate) fn at_origin(nx: u32, ny: u32, nz: u32, voxel_size: f32) -> Self {
+        Self::new(
+            String::new(),
+            nx,
+            ny,
+            nz,
+            Vector3::ZERO,
+            voxel_size,
+            Quaternion::IDENTITY,
+        )
+    }
+
+    pub fn voxel_count(&self) -> u64 {
+        self.nx as u64 * self.ny as u64 * self.nz as u64
+    }
+
+    /// The voxel at linear index `idx`, the inverse of [`Voxel::idx`].
+    pub fn voxel(&self, idx: u64) -> Voxel {
+        let (nx, ny) = (self.nx as u64, self.ny as u64);
+        Voxel {
+            x: (idx % nx) as u32,
+            y: (idx / nx % ny) as u32,
+            z: (idx / (nx * ny)) as u32,
+        }
+    }
+
+    /// World position of the grid's center.
+    pub fn center(&self) -> Vector3 {
+        self.to_world(Vector3 {
+            x: self.nx as f32 * 0.5,
+            y: self.ny as f32 * 0.5,
+            z: self.nz as f32 * 0.5,
+        })
+    }
+
+    /// World position of the center of `voxel`.
+    pub fn voxel_center(&self, voxel: &Voxel) -> Vector3 {
+        self.to_world(Vector3 {
+            x: voxel.x as f32 + 0.5,
+            y: voxel.y as f32 + 0.5,
+            z: voxel.z as f32 + 0.5,
+        })
+    }
+
+    /// World position of a point given in voxel units along the grid's axes, so that voxel
+    /// (x, y, z) spans `x..x + 1` and so on.
+    pub fn to_world(&self, point: Vector3) -> Vector3 {
+        let local = Vector3 {
+            x: point.x * self.voxel_size,
+            y: point.y * self.voxel_size,
+            z: point.z * self.voxel_size,
+        };
+        let offset = self.orientation.rotate(local);
+        Vector3 {
+            x: self.origin.x + offset.x,
+            y: self.origin.y + offset.y,
+            z: self.origin.z + offset.z,
+        }
+    }
+}
+
+/// The accumulated value of one voxel of a grid. Voxels without a row are zero.
+#[table(
+    name = voxel_value,
+    public,
+    index(name = grid_voxel, btree(columns = [grid_id, voxel]))
+)]
+pub struct VoxelValue {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub grid_id: u32,
+    /// Linear index of the voxel, see [`Voxel::idx`].
+    pub voxel: u64,
+    /// The value as of `updated_at`, see [`AggregationMode`]. Decay to other times with [`decay`].
+    pub value: f32,
+    pub updated_at: Timestamp,
+    /// Capture time of the latest frame that hit the voxel, or for voxels only ever missed, that
+    /// first missed it.
+    pub last_hit: Timestamp,
+    /// The camera that last hit the voxel.
+    pub last_camera: Identity,
+    /// Distinct cameras that hit the voxel within the grid's consensus window, as of `last_hit`.
+    /// Always 1 in grids whose consensus doesn't gate.
+    pub cameras: u32,
+}
+
+/// Decays `value` from `then` to `now` with the given half-life in seconds.
+fn decay(value: f32, then: Timestamp, now: Timestamp, half_life: f32) -> f32 {
+    let dt_micros = now.to_micros_since_unix_epoch() - then.to_micros_since_unix_epoch();
+    let dt = dt_micros.max(0) as f32 / 1_000_000.0;
+    let tau = half_life / std::f32::consts::LN_2;
+    value * (-dt / tau).exp()
 }
 
 #[spacetimedb::reducer(init)]
 pub fn init(ctx: &ReducerContext) -> Result<(), String> {
-    let new_grid = vec![0.0; (GRID_SIZE * GRID_SIZE * GRID_SIZE)];
-    ctx.db.voxel_grid().try_insert(VoxelGrid {
-        id: 0,
-        voxel_size: 1.0,
-        grid: new_grid,
-    })?;
+    auth::bootstrap_admin(ctx);
+    ensure_grids(ctx);
     Ok(())
 }
 
+/// Creates the default grid if there is no grid at all and (re)starts every grid's scheduled
+/// passes. Safe to run any number of times.
+fn ensure_grids(ctx: &ReducerContext) {
+    if ctx.db.voxel_grid().count() == 0 {
+        ctx.db.voxel_grid().insert(VoxelGrid::new(
+            DEFAULT_GRID_NAME.to_string(),
+            DEFAULT_GRID_SIZE,
+            DEFAULT_GRID_SIZE,
+            DEFAULT_GRID_SIZE,
+            Vector3::ZERO,
+            1.0,
+            Quaternion::IDENTITY,
+        ));
+    }
+    for grid in ctx.db.voxel_grid().iter() {
+        detector::schedule_detection(ctx, &grid);
+        schedule_maintenance(ctx, &grid);
+    }
+}
+
 #[spacetimedb::reducer(client_connected)]
-pub fn identity_connected(_ctx: &ReducerContext) {
-    // Called everytime a new client connects
+pub fn identity_connected(ctx: &ReducerContext) {
+    sessions::connect(ctx);
 }
 
 #[spacetimedb::reducer(client_disconnected)]
-pub fn identity_disconnected(_ctx: &ReducerContext) {
-    // Called everytime a client disconnects
+pub fn identity_disconnected(ctx: &ReducerContext) {
+    sessions::disconnect(ctx);
 }
 
+/// Applies a single hit to grid `grid_id`, taken to be captured now. An invalid hit is counted
+/// against the camera like one in [`update_voxels`] rather than failing, which would roll back the
+/// count.
 #[spacetimedb::reducer]
-pub fn update_voxel(ctx: &ReducerContext, voxel: Voxel, value: f32) -> Result<(), String> {
-    log::info!("voxel update");
-    let idx = voxel.idx(GRID_SIZE.try_into().unwrap());
-    for mut grid in ctx.db.voxel_grid().iter() {
-        if idx < grid.grid.len().try_into().unwrap() {
-            grid.grid[idx] = value;
-            ctx.db.voxel_grid().id().update(grid);
+pub fn update_voxel(
+    ctx: &ReducerContext,
+    grid_id: u32,
+    voxel: Voxel,
+    value: f32,
+) -> Result<(), String> {
+    auth::require_role(ctx, Role::Camera)?;
+    let camera = cameras::record_frame(ctx, 1)?;
+    let grid = coverage::covered_grid(ctx, grid_id)?;
+    if camera.muted {
+        return Ok(());
+    }
+    let outcome = apply_hits(
+        ctx,
+        &camera,
+        &grid,
+        ctx.timestamp,
+        &[VoxelHit { voxel, value }],
+        &[],
+    );
+    record_outcome(ctx, camera, 1, outcome);
+    Ok(())
+}
+
+/// Applies every hit a camera found in one frame of grid `grid_id`, which the camera must cover,
+/// in a single transaction. `captured_at` is when the camera grabbed the frame. `misses` are
+/// voxels the frame's rays passed through without hitting, which only log-odds grids use. Invalid
+/// hits are skipped and counted against the camera rather than failing the frame, as is every
+/// hit of a stale frame.
+#[spacetimedb::reducer]
+pub fn update_voxels(
+    ctx: &ReducerContext,
+    grid_id: u32,
+    frame_id: u64,
+    captured_at: Timestamp,
+    hits: Vec<VoxelHit>,
+    misses: Vec<Voxel>,
+) -> Result<(), String> {
+    auth::require_role(ctx, Role::Camera)?;
+    let camera = cameras::record_frame(ctx, hits.len() + misses.len())?;
+    let grid = coverage::covered_grid(ctx, grid_id)?;
+    log::debug!(
+        "{} frame {frame_id} of grid {grid_id}: {} voxel hits, {} misses",
+        camera.name,
+        hits.len(),
+        misses.len()
+    );
+    if camera.muted {
+        return Ok(());
+    }
+    let outcome = apply_hits(ctx, &camera, &grid, captured_at, &hits, &misses);
+    record_outcome(ctx, camera, hits.len() + misses.len(), outcome);
+    Ok(())
+}
+
+/// Counts what [`apply_hits`] rejected of a frame of `observations` hits and misses against
+/// `camera`. A stale frame counts as rejected as a whole, rather than failing the reducer and
+/// rolling back the frame's stats, so a camera with a bad clock shows up in them.
+fn record_outcome(
+    ctx: &ReducerContext,
+    camera: cameras::Camera,
+    observations: usize,
+    outcome: Result<Vec<VoxelError>, VoxelError>,
+) {
+    let (rejected, error) = match outcome {
+        Ok(mut rejected) => (rejected.len(), rejected.pop()),
+        Err(error) => (observations, Some(error)),
+    };
+    if let Some(error) = error {
+        log::warn!(
+            "{}: rejected {rejected} of {observations} voxel hits and misses: {error}",
+            camera.name
+        );
+        cameras::record_rejections(ctx, camera, rejected, &error);
+    }
+}
+
+/// Applies each hit and miss of a frame `camera` captured at `captured_at` to `grid`, scaled by
+/// the camera's weight, and returns why the others were rejected. A frame older than the grid's
+/// horizon is rejected as a whole.
+fn apply_hits(
+    ctx: &ReducerContext,
+    camera: &cameras::Camera,
+    grid: &VoxelGrid,
+    captured_at: Timestamp,
+    hits: &[VoxelHit],
+    misses: &[Voxel],
+) -> Result<Vec<VoxelError>, VoxelError> {
+    // A camera clock running ahead must not make its hits outlive everyone else's.
+    let captured_at = captured_at.min(ctx.timestamp);
+    let age_ms = (ctx.timestamp.to_micros_since_unix_epoch()
+        - captured_at.to_micros_since_unix_epoch())
+        / 1000;
+    if age_ms > grid.frame_horizon_ms as i64 {
+        return Err(VoxelError::StaleFrame {
+            grid_id: grid.id,
+            age_ms,
+            horizon_ms: grid.frame_horizon_ms,
+        });
+    }
+    let weight = cameras::hit_weight(camera, grid, &coverage::covering_cameras(ctx, grid.id));
+
+    let mut rejected = Vec::new();
+    // Hits carry a value, misses don't.
+    let observations = hits
+        .iter()
+        .map(|hit| (&hit.voxel, Some(hit)))
+        .chain(misses.iter().map(|voxel| (voxel, None)));
+    for (voxel, hit) in observations {
+        if let Some(Err(error)) = hit.map(VoxelHit::validate_value) {
+            rejected.push(error);
+            continue;
+        }
+        let idx = match voxel.checked_idx(grid) {
+            Ok(idx) => idx,
+            Err(error) => {
+                rejected.push(error);
+                continue;
+            }
+        };
+        if weight <= 0.0 {
+            continue;
+        }
+        match hit {
+            Some(hit) => {
+                let delta = grid.aggregation.hit_delta(hit.value, weight);
+                add_to_voxel(ctx, grid, idx, delta, captured_at, true);
+            }
+            None => {
+                if let Some(delta) = grid.aggregation.miss_delta(weight) {
+                    add_to_voxel(ctx, grid, idx, delta, captured_at, false);
+                }
+            }
+        }
+    }
+    Ok(rejected)
+}
+
+/// Adds `delta` to `voxel` for an observation captured at `at`, which was a hit if `hit` and a
+/// ray passing through otherwise.
+fn add_to_voxel(
+    ctx: &ReducerContext,
+    grid: &VoxelGrid,
+    voxel: u64,
+    delta: f32,
+    at: Timestamp,
+    hit: bool,
+) {
+    let cameras = hit.then(|| consensus::record_contribution(ctx, grid, voxel, at));
+    let voxel_values = ctx.db.voxel_value();
+    match voxel_values.grid_voxel().filter((grid.id, voxel)).next() {
+        Some(mut row) => {
+            // Frames from different cameras arrive out of order, so bring the stored value and the
+            // observation to whichever of them is later before adding.
+            let now = row.updated_at.max(at);
+            row.value = grid.aggregation.clamp(
+                decay(row.value, row.updated_at, now, grid.half_life)
+                    + decay(delta, at, now, grid.half_life),
+            );
+            row.updated_at = now;
+            if let Some(cameras) = cameras {
+                row.last_hit = row.last_hit.max(at);
+                row.last_camera = ctx.sender;
+                row.cameras = cameras;
+            }
+            if row.value.abs() < PRUNE_EPSILON {
+                voxel_values.id().delete(row.id);
+            } else {
+                voxel_values.id().update(row);
+            }
         }
+        None => {
+            let value = grid.aggregation.clamp(delta);
+            if value.abs() >= PRUNE_EPSILON {
+                voxel_values.insert(VoxelValue {
+                    id: 0,
+                    grid_id: grid.id,
+                    voxel,
+                    value,
+                    updated_at: at,
+                    last_hit: at,
+                    last_camera: ctx.sender,
+                    cameras: cameras.unwrap_or(0),
+                });
+            }
+        }
+    }
+}
+
+/// Checks a grid's geometry and returns its orientation normalized.
+fn validate_geometry(
+    nx: u32,
+    ny: u32,
+    nz: u32,
+    origin: Vector3,
+    voxel_size: f32,
+    orientation: Quaternion,
+) -> Result<Quaternion, String> {
+    if nx == 0 || ny == 0 || nz == 0 {
+        return Err(format!(
+            "grid dimensions must be non-zero, got {nx}x{ny}x{nz}"
+        ));
+    }
+    let voxels = (nx as u64 * ny as u64).checked_mul(nz as u64);
+    if !voxels.is_some_and(|voxels| voxels <= MAX_GRID_VOXELS) {
+        return Err(format!(
+            "grid of {nx}x{ny}x{nz} voxels has more than the {MAX_GRID_VOXELS} allowed"
+        ));
+    }
+    if !origin.is_finite() {
+        return Err("grid origin must be finite".to_string());
+    }
+    if !(voxel_size.is_finite() && voxel_size > 0.0) {
+        return Err(format!("voxel size must be positive, got {voxel_size}"));
+    }
+    orientation
+        .normalized()
+        .ok_or_else(|| "grid orientation must be a non-zero quaternion".to_string())
+}
+
+/// Creates a grid named `name` with default settings and starts its scheduled passes.
+#[spacetimedb::reducer]
+pub fn create_grid(
+    ctx: &ReducerContext,
+    name: String,
+    nx: u32,
+    ny: u32,
+    nz: u32,
+    origin: Vector3,
+    voxel_size: f32,
+    orientation: Quaternion,
+) -> Result<(), String> {
+    auth::require_role(ctx, Role::Admin)?;
+    if name.trim().is_empty() {
+        return Err("grid name must not be empty".to_string());
+    }
+    if ctx.db.voxel_grid().name().find(&name).is_some() {
+        return Err(format!("a voxel grid named {name:?} already exists"));
+    }
+    let orientation = validate_geometry(nx, ny, nz, origin, voxel_size, orientation)?;
+    let grid = ctx.db.voxel_grid().insert(VoxelGrid::new(
+        name,
+        nx,
+        ny,
+        nz,
+        origin,
+        voxel_size,
+        orientation,
+    ));
+    detector::schedule_detection(ctx, &grid);
+    schedule_maintenance(ctx, &grid);
+    log::info!(
+        "created grid {} {:?} as {nx}x{ny}x{nz} voxels of size {voxel_size}",
+        grid.id,
+        grid.name
+    );
+    Ok(())
+}
+
+/// Changes the geometry of grid `grid_id`. Resizing a grid discards its values, since their
+/// indices no longer refer to the same voxels.
+#[spacetimedb::reducer]
+pub fn configure_grid(
+    ctx: &ReducerContext,
+    grid_id: u32,
+    nx: u32,
+    ny: u32,
+    nz: u32,
+    origin: Vector3,
+    voxel_size: f32,
+    orientation: Quaternion,
+) -> Result<(), String> {
+    auth::require_role(ctx, Role::Admin)?;
+    let orientation = validate_geometry(nx, ny, nz, origin, voxel_size, orientation)?;
+    let mut grid = ctx
+        .db
+        .voxel_grid()
+        .id()
+        .find(grid_id)
+        .ok_or_else(|| format!("no voxel grid with id {grid_id}"))?;
+    if (grid.nx, grid.ny, grid.nz) != (nx, ny, nz) {
+        clear_voxel_values(ctx, grid_id);
+    }
+    grid.nx = nx;
+    grid.ny = ny;
+    grid.nz = nz;
+    grid.origin = origin;
+    grid.voxel_size = voxel_size;
+    grid.orientation = orientation;
+    ctx.db.voxel_grid().id().update(grid);
+    log::info!("configured grid {grid_id} as {nx}x{ny}x{nz} voxels of size {voxel_size}");
+    Ok(())
+}
+
+/// Removes grid `grid_id` along with everything recorded in it and the cameras' coverage of it.
+/// Its scheduled passes stop the next time they find the grid gone.
+#[spacetimedb::reducer]
+pub fn delete_grid(ctx: &ReducerContext, grid_id: u32) -> Result<(), String> {
+    auth::require_role(ctx, Role::Admin)?;
+    let grid = ctx
+        .db
+        .voxel_grid()
+        .id()
+        .find(grid_id)
+        .ok_or_else(|| format!("no voxel grid with id {grid_id}"))?;
+    reset_grid_state(ctx, grid_id);
+    coverage::clear_coverage(ctx, grid_id);
+    ctx.db.voxel_grid().id().delete(grid_id);
+    log::info!("{} deleted grid {grid_id} {:?}", ctx.sender, grid.name);
+    Ok(())
+}
+
+fn clear_voxel_values(ctx: &ReducerContext, grid_id: u32) {
+    let ids: Vec<u64> = ctx
+        .db
+        .voxel_value()
+        .grid_voxel()
+        .filter(grid_id)
+        .map(|row| row.id)
+        .collect();
+    for id in ids {
+        ctx.db.voxel_value().id().delete(id);
+    }
+    consensus::clear_contributions(ctx, grid_id);
+}
+
+/// Removes everything the grid's cameras and detection passes have produced, keeping its geometry
+/// and settings.
+fn reset_grid_state(ctx: &ReducerContext, grid_id: u32) {
+    clear_voxel_values(ctx, grid_id);
+    detector::clear_detections(ctx, grid_id);
+    clustering::clear_objects(ctx, grid_id);
+    tracking::clear_tracks(ctx, grid_id);
+    ctx.db.grid_maintenance().grid_id().delete(grid_id);
+}
+
+/// Clears the grid's values, detections, objects, tracks and maintenance statistics, e.g. after
+/// cameras were moved.
+#[spacetimedb::reducer]
+pub fn reset_grid(ctx: &ReducerContext, grid_id: u32) -> Result<(), String> {
+    auth::require_role(ctx, Role::Admin)?;
+    if ctx.db.voxel_grid().id().find(grid_id).is_none() {
+        return Err(format!("no voxel grid with id {grid_id}"));
+    }
+    reset_grid_state(ctx, grid_id);
+    log::info!("{} reset grid {grid_id}", ctx.sender);
+    Ok(())
+}
+
+/// Clears the values of the voxels from `min` to `max`, inclusive on every axis. Detections,
+/// objects and tracks catch up on the next detection pass.
+#[spacetimedb::reducer]
+pub fn clear_region(
+    ctx: &ReducerContext,
+    grid_id: u32,
+    min: Voxel,
+    max: Voxel,
+) -> Result<(), String> {
+    auth::require_role(ctx, Role::Admin)?;
+    let grid = ctx
+        .db
+        .voxel_grid()
+        .id()
+        .find(grid_id)
+        .ok_or_else(|| format!("no voxel grid with id {grid_id}"))?;
+    min.checked_idx(&grid)?;
+    max.checked_idx(&grid)?;
+    if min.x > max.x || min.y > max.y || min.z > max.z {
+        return Err(format!(
+            "region corners must be ordered, got ({}, {}, {}) to ({}, {}, {})",
+            min.x, min.y, min.z, max.x, max.y, max.z
+        ));
+    }
+    let cleared: Vec<(u64, u64)> = ctx
+        .db
+        .voxel_value()
+        .grid_voxel()
+        .filter(grid_id)
+        .filter(|row| grid.voxel(row.voxel).within(&min, &max))
+        .map(|row| (row.id, row.voxel))
+        .collect();
+    for &(id, voxel) in &cleared {
+        ctx.db.voxel_value().id().delete(id);
+        consensus::clear_voxel(ctx, grid_id, voxel);
+    }
+    log::info!(
+        "{} cleared {} voxel values from grid {grid_id}",
+        ctx.sender,
+        cleared.len()
+    );
+    Ok(())
+}
+
+/// Brings the module back to its state after `init`, keeping grids' geometry and settings: every
+/// grid is reset, the default grid is created if there is none and all scheduled passes are
+/// restarted. Running it again changes nothing.
+#[spacetimedb::reducer]
+pub fn reinitialize(ctx: &ReducerContext) -> Result<(), String> {
+    auth::require_role(ctx, Role::Admin)?;
+    let grid_ids: Vec<u32> = ctx.db.voxel_grid().iter().map(|grid| grid.id).collect();
+    for &grid_id in &grid_ids {
+        reset_grid_state(ctx, grid_id);
+    }
+    ensure_grids(ctx);
+    log::info!("{} reinitialized {} grids", ctx.sender, grid_ids.len());
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_half_life(ctx: &ReducerContext, grid_id: u32, half_life: f32) -> Result<(), String> {
+    auth::require_role(ctx, Role::Admin)?;
+    if !(half_life.is_finite() && half_life > 0.0) {
+        return Err(format!(
+            "half-life must be a positive number of seconds, got {half_life}"
+        ));
+    }
+    let mut grid = ctx
+        .db
+        .voxel_grid()
+        .id()
+        .find(grid_id)
+        .ok_or_else(|| format!("no voxel grid with id {grid_id}"))?;
+    grid.half_life = half_life;
+    ctx.db.voxel_grid().id().update(grid);
+    Ok(())
+}
+
+#[table(name = maintenance_schedule, scheduled(run_maintenance))]
+pub struct MaintenanceSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+    #[unique]
+    pub grid_id: u32,
+}
+
+/// The outcome of the latest maintenance pass over a grid.
+#[table(name = grid_maintenance, public)]
+pub struct GridMaintenance {
+    #[primary_key]
+    pub grid_id: u32,
+    pub ran_at: Timestamp,
+    /// Voxel values that had decayed below the prune threshold and were removed.
+    pub pruned: u64,
+    /// Voxel values left after pruning.
+    pub remaining: u64,
+    /// Camera contributions that fell out of the consensus window and were removed.
+    pub pruned_contributions: u64,
+    /// Voxel values pruned by all passes so far.
+    pub total_pruned: u64,
+}
+
+/// Starts, or restarts with the grid's current interval, the maintenance passes over `grid`.
+fn schedule_maintenance(ctx: &ReducerContext, grid: &VoxelGrid) {
+    ctx.db.maintenance_schedule().grid_id().delete(grid.id);
+    ctx.db.maintenance_schedule().insert(MaintenanceSchedule {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(
+            Duration::from_millis(grid.maintenance_interval_ms).into(),
+        ),
+        grid_id: grid.id,
+    });
+}
+
+#[spacetimedb::reducer]
+pub fn set_frame_horizon(
+    ctx: &ReducerContext,
+    grid_id: u32,
+    horizon_ms: u64,
+) -> Result<(), String> {
+    auth::require_role(ctx, Role::Admin)?;
+    if horizon_ms == 0 {
+        return Err("frame horizon must be non-zero".to_string());
+    }
+    let mut grid = ctx
+        .db
+        .voxel_grid()
+        .id()
+        .find(grid_id)
+        .ok_or_else(|| format!("no voxel grid with id {grid_id}"))?;
+    grid.frame_horizon_ms = horizon_ms;
+    ctx.db.voxel_grid().id().update(grid);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_maintenance_interval(
+    ctx: &ReducerContext,
+    grid_id: u32,
+    interval_ms: u64,
+) -> Result<(), String> {
+    auth::require_role(ctx, Role::Admin)?;
+    if interval_ms == 0 {
+        return Err("maintenance interval must be non-zero".to_string());
+    }
+    let mut grid = ctx
+        .db
+        .voxel_grid()
+        .id()
+        .find(grid_id)
+        .ok_or_else(|| format!("no voxel grid with id {grid_id}"))?;
+    grid.maintenance_interval_ms = interval_ms;
+    let grid = ctx.db.voxel_grid().id().update(grid);
+    schedule_maintenance(ctx, &grid);
+    Ok(())
+}
+
+/// Removes the values of the grid that decayed to within [`PRUNE_EPSILON`] of zero by now, so
+/// voxels no camera observes any more return to zero. Live values are left as stored; they decay
+/// lazily wherever they are read.
+#[spacetimedb::reducer]
+pub fn run_maintenance(ctx: &ReducerContext, schedule: MaintenanceSchedule) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("run_maintenance may only be invoked by the scheduler".to_string());
+    }
+    let Some(grid) = ctx.db.voxel_grid().id().find(schedule.grid_id) else {
+        ctx.db
+            .maintenance_schedule()
+            .scheduled_id()
+            .delete(schedule.scheduled_id);
+        return Ok(());
+    };
+
+    let rows: Vec<VoxelValue> = ctx.db.voxel_value().grid_voxel().filter(grid.id).collect();
+    let (mut pruned, mut remaining) = (0, 0);
+    for row in rows {
+        if decay(row.value, row.updated_at, ctx.timestamp, grid.half_life).abs() < PRUNE_EPSILON {
+            ctx.db.voxel_value().id().delete(row.id);
+            pruned += 1;
+        } else {
+            remaining += 1;
+        }
+    }
+    let pruned_contributions = consensus::prune_contributions(ctx, &grid);
+
+    let previous = ctx.db.grid_maintenance().grid_id().find(grid.id);
+    let stats = GridMaintenance {
+        grid_id: grid.id,
+        ran_at: ctx.timestamp,
+        pruned,
+        remaining,
+        pruned_contributions,
+        total_pruned: previous.as_ref().map_or(0, |stats| stats.total_pruned) + pruned,
+    };
+    if previous.is_some() {
+        ctx.db.grid_maintenance().grid_id().update(stats);
+    } else {
+        ctx.db.grid_maintenance().insert(stats);
+    }
+    if pruned > 0 {
+        log::debug!("grid {}: pruned {pruned} voxels, {remaining} left", grid.id);
     }
     Ok(())
 }
@@ -0,0 +1,102 @@
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp, table};
+
+use crate::auth::{Role, authorized_identity};
+use crate::cameras;
+
+/// A client's latest connection. Rows outlive the connection so the last one stays visible.
+#[table(name = client_session, public)]
+pub struct ClientSession {
+    #[primary_key]
+    pub identity: Identity,
+    pub online: bool,
+    pub connected_at: Timestamp,
+    pub disconnected_at: Option<Timestamp>,
+    /// When the client last connected or called a reducer.
+    pub last_seen: Timestamp,
+    /// The role the identity holds, if any.
+    pub role: Option<Role>,
+    /// Version the client reported with `set_client_version`.
+    pub client_version: Option<String>,
+}
+
+fn role_of(ctx: &ReducerContext, identity: Identity) -> Option<Role> {
+    ctx.db
+        .authorized_identity()
+        .identity()
+        .find(identity)
+        .map(|authorized| authorized.role)
+}
+
+pub(crate) fn connect(ctx: &ReducerContext) {
+    let session = ClientSession {
+        identity: ctx.sender,
+        online: true,
+        connected_at: ctx.timestamp,
+        disconnected_at: None,
+        last_seen: ctx.timestamp,
+        role: role_of(ctx, ctx.sender),
+        client_version: None,
+    };
+    if ctx
+        .db
+        .client_session()
+        .identity()
+        .find(ctx.sender)
+        .is_some()
+    {
+        ctx.db.client_session().identity().update(session);
+    } else {
+        ctx.db.client_session().insert(session);
+    }
+    cameras::set_online(ctx, ctx.sender, true);
+}
+
+pub(crate) fn disconnect(ctx: &ReducerContext) {
+    if let Some(session) = ctx.db.client_session().identity().find(ctx.sender) {
+        ctx.db.client_session().identity().update(ClientSession {
+            online: false,
+            disconnected_at: Some(ctx.timestamp),
+            last_seen: ctx.timestamp,
+            ..session
+        });
+    }
+    cameras::set_online(ctx, ctx.sender, false);
+}
+
+/// Records that the caller is still around.
+pub(crate) fn touch(ctx: &ReducerContext) {
+    if let Some(session) = ctx.db.client_session().identity().find(ctx.sender) {
+        ctx.db.client_session().identity().update(ClientSession {
+            last_seen: ctx.timestamp,
+            ..session
+        });
+    }
+}
+
+/// Refreshes the role shown in `identity`'s session after it was granted or revoked.
+pub(crate) fn sync_role(ctx: &ReducerContext, identity: Identity) {
+    if let Some(session) = ctx.db.client_session().identity().find(identity) {
+        ctx.db.client_session().identity().update(ClientSession {
+            role: role_of(ctx, identity),
+            ..session
+        });
+    }
+}
+
+/// Lets any connected client report its version. Needs no role, as it only touches the caller's
+/// own session.
+#[spacetimedb::reducer]
+pub fn set_client_version(ctx: &ReducerContext, version: String) -> Result<(), String> {
+    let session = ctx
+        .db
+        .client_session()
+        .identity()
+        .find(ctx.sender)
+        .ok_or_else(|| format!("{} is not connected", ctx.sender))?;
+    ctx.db.client_session().identity().update(ClientSession {
+        client_version: Some(version),
+        last_seen: ctx.timestamp,
+        ..session
+    });
+    Ok(())
+}
@@ -0,0 +1,378 @@
+use spacetimedb::{ReducerContext, SpacetimeType, Table, Timestamp, table};
+
+use crate::auth::{self, Role};
+use crate::clustering::Object;
+use crate::{Vector3, VoxelGrid, voxel_grid};
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq, Debug)]
+pub struct TrackerConfig {
+    /// Furthest, in world units, an object's centroid may be from a track's predicted position
+    /// for the two to be associated.
+    pub gate_distance: f32,
+    /// Associations a tentative track needs before it is confirmed.
+    pub confirm_hits: u32,
+    /// Consecutive misses after which a confirmed track is marked lost.
+    pub lost_misses: u32,
+    /// Consecutive misses after which a lost track is dropped.
+    pub drop_misses: u32,
+    /// Standard deviation of the unmodelled acceleration, in world units per second squared.
+    pub acceleration_noise: f32,
+    /// Standard deviation of object centroids around the true position, in world units.
+    pub measurement_noise: f32,
+}
+impl Default for TrackerConfig {
+    fn default() -> Self {
+        Self {
+            gate_distance: 3.0,
+            confirm_hits: 3,
+            lost_misses: 5,
+            drop_misses: 25,
+            acceleration_noise: 2.0,
+            measurement_noise: 0.5,
+        }
+    }
+}
+
+/// Standard deviation of a new track's velocity, in world units per second.
+const INITIAL_SPEED_STD: f32 = 2.0;
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TrackStatus {
+    /// Seen too few times to be trusted. Dropped on its first miss.
+    Tentative,
+    Confirmed,
+    /// Confirmed, but not seen for a while. Position and velocity are predictions.
+    Lost,
+}
+
+/// Covariance of one axis of a track's constant-velocity state. All three axes share it, as they
+/// are filtered independently with the same model and measurements.
+#[derive(SpacetimeType, Clone, Copy, PartialEq, Debug)]
+pub struct AxisCovariance {
+    /// Position variance.
+    pub pp: f32,
+    /// Position-velocity covariance.
+    pub pv: f32,
+    /// Velocity variance.
+    pub vv: f32,
+}
+
+/// An object followed across detection passes. The id stays the same for as long as the object
+/// keeps being associated with the track.
+#[table(name = track, public)]
+pub struct Track {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub grid_id: u32,
+    pub status: TrackStatus,
+    /// Estimated world position as of `updated_at`.
+    pub position: Vector3,
+    /// Estimated velocity in world units per second.
+    pub velocity: Vector3,
+    pub covariance: AxisCovariance,
+    /// The object the track was associated with in the latest pass, if any.
+    pub object_id: Option<u64>,
+    /// Detection passes since the track was created.
+    pub age: u32,
+    /// Passes in which the track was associated with an object.
+    pub hits: u32,
+    /// Consecutive passes without an associated object.
+    pub misses: u32,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+impl Track {
+    /// A new track for an object no existing track claimed, seen once at `at`.
+    fn spawn(grid_id: u32, object: &Object, config: &TrackerConfig, at: Timestamp) -> Self {
+        let position_variance = config.measurement_noise * config.measurement_noise;
+        Self {
+            id: 0,
+            grid_id,
+            status: if config.confirm_hits <= 1 {
+                TrackStatus::Confirmed
+            } else {
+                TrackStatus::Tentative
+            },
+            position: object.centroid,
+            velocity: Vector3::ZERO,
+            covariance: AxisCovariance {
+                pp: position_variance,
+                pv: 0.0,
+                vv: INITIAL_SPEED_STD * INITIAL_SPEED_STD,
+            },
+            object_id: Some(object.id),
+            age: 0,
+            hits: 1,
+            misses: 0,
+            created_at: at,
+            updated_at: at,
+        }
+    }
+
+    /// Advances the state `dt` seconds along the constant-velocity model.
+    fn predict(&mut self, dt: f32, acceleration_noise: f32) {
+        let q = acceleration_noise * acceleration_noise;
+        let AxisCovariance { pp, pv, vv } = self.covariance;
+        self.covariance = AxisCovariance {
+            pp: pp + 2.0 * dt * pv + dt * dt * vv + q * dt.powi(4) / 4.0,
+            pv: pv + dt * vv + q * dt.powi(3) / 2.0,
+            vv: vv + q * dt * dt,
+        };
+        self.position = map2(self.position, self.velocity, |p, v| p + v * dt);
+    }
+
+    /// Folds a measured position into the state.
+    fn correct(&mut self, measured: Vector3, measurement_noise: f32) {
+        let AxisCovariance { pp, pv, vv } = self.covariance;
+        let innovation_variance = pp + measurement_noise * measurement_noise;
+        let (kp, kv) = (pp / innovation_variance, pv / innovation_variance);
+        let residual = map2(measured, self.position, |z, p| z - p);
+        self.position = map2(self.position, residual, |p, r| p + kp * r);
+        self.velocity = map2(self.velocity, residual, |v, r| v + kv * r);
+        self.covariance = AxisCovariance {
+            pp: (1.0 - kp) * pp,
+            pv: (1.0 - kp) * pv,
+            vv: vv - kv * pv,
+        };
+    }
+}
+
+fn map2(a: Vector3, b: Vector3, f: impl Fn(f32, f32) -> f32) -> Vector3 {
+    Vector3 {
+        x: f(a.x, b.x),
+        y: f(a.y, b.y),
+        z: f(a.z, b.z),
+    }
+}
+
+/// Pairs each track with at most one object and vice versa, closest pairs first, ignoring pairs
+/// further apart than `gate`. Returns `(track, object)` index pairs.
+pub fn associate(tracks: &[Vector3], objects: &[Vector3], gate: f32) -> Vec<(usize, usize)> {
+    let mut candidates = Vec::new();
+    for (t, &track) in tracks.iter().enumerate() {
+        for (o, &object) in objects.iter().enumerate() {
+            let d = track.distance(object);
+            if d <= gate {
+                candidates.push((d, t, o));
+            }
+        }
+    }
+    candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut track_taken = vec![false; tracks.len()];
+    let mut object_taken = vec![false; objects.len()];
+    let mut pairs = Vec::new();
+    for (_, t, o) in candidates {
+        if !track_taken[t] && !object_taken[o] {
+            track_taken[t] = true;
+            object_taken[o] = true;
+            pairs.push((t, o));
+        }
+    }
+    pairs
+}
+
+/// Indices of the `objects` objects no pair returned by [`associate`] claims.
+fn unclaimed(objects: usize, pairs: &[(usize, usize)]) -> Vec<usize> {
+    let mut claimed = vec![false; objects];
+    for &(_, o) in pairs {
+        claimed[o] = true;
+    }
+    (0..objects).filter(|&o| !claimed[o]).collect()
+}
+
+#[spacetimedb::reducer]
+pub fn configure_tracking(
+    ctx: &ReducerContext,
+    grid_id: u32,
+    config: TrackerConfig,
+) -> Result<(), String> {
+    auth::require_role(ctx, Role::Admin)?;
+    let positive = |v: f32| v.is_finite() && v > 0.0;
+    if !(positive(config.gate_distance)
+        && positive(config.acceleration_noise)
+        && positive(config.measurement_noise))
+    {
+        return Err("gate distance and noise levels must be positive".to_string());
+    }
+    if config.confirm_hits == 0 || config.lost_misses == 0 {
+        return Err("confirm_hits and lost_misses must be non-zero".to_string());
+    }
+    if config.drop_misses < config.lost_misses {
+        return Err(format!(
+            "drop_misses ({}) must be at least lost_misses ({})",
+            config.drop_misses, config.lost_misses
+        ));
+    }
+    let mut grid = ctx
+        .db
+        .voxel_grid()
+        .id()
+        .find(grid_id)
+        .ok_or_else(|| format!("no voxel grid with id {grid_id}"))?;
+    grid.tracking = config;
+    ctx.db.voxel_grid().id().update(grid);
+    Ok(())
+}
+
+/// Advances `grid`'s tracks to now and associates them with the pass's `objects`. Objects no
+/// track claims start new tentative tracks.
+pub(crate) fn update_tracks(ctx: &ReducerContext, grid: &VoxelGrid, objects: &[Object]) {
+    let config = grid.tracking;
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let mut tracks: Vec<Track> = ctx.db.track().grid_id().filter(grid.id).collect();
+    for track in &mut tracks {
+        let dt_micros = now - track.updated_at.to_micros_since_unix_epoch();
+        track.predict(
+            dt_micros.max(0) as f32 / 1_000_000.0,
+            config.acceleration_noise,
+        );
+        track.updated_at = ctx.timestamp;
+        track.age += 1;
+        track.object_id = None;
+    }
+
+    let predicted: Vec<Vector3> = tracks.iter().map(|track| track.position).collect();
+    let centroids: Vec<Vector3> = objects.iter().map(|object| object.centroid).collect();
+    let pairs = associate(&predicted, &centroids, config.gate_distance);
+    for &(t, o) in &pairs {
+        let (track, object) = (&mut tracks[t], &objects[o]);
+        track.correct(object.centroid, config.measurement_noise);
+        track.object_id = Some(object.id);
+        track.hits += 1;
+        track.misses = 0;
+    }
+
+    for mut track in tracks {
+        if track.object_id.is_some() {
+            if track.status == TrackStatus::Lost || track.hits >= config.confirm_hits {
+                track.status = TrackStatus::Confirmed;
+            }
+        } else {
+            track.misses += 1;
+            let dropped = match track.status {
+                TrackStatus::Tentative => true,
+                TrackStatus::Confirmed | TrackStatus::Lost => track.misses >= config.drop_misses,
+            };
+            if dropped {
+                ctx.db.track().id().delete(track.id);
+                continue;
+            }
+            if track.misses >= config.lost_misses {
+                track.status = TrackStatus::Lost;
+            }
+        }
+        ctx.db.track().id().update(track);
+    }
+
+    for o in unclaimed(objects.len(), &pairs) {
+        ctx.db
+            .track()
+            .insert(Track::spawn(grid.id, &objects[o], &config, ctx.timestamp));
+    }
+}
+
+pub(crate) fn clear_tracks(ctx: &ReducerContext, grid_id: u32) {
+    let ids: Vec<u64> = ctx
+        .db
+        .track()
+        .grid_id()
+        .filter(grid_id)
+        .map(|row| row.id)
+        .collect();
+    for id in ids {
+        ctx.db.track().id().delete(id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Voxel;
+
+    fn point(x: f32, y: f32, z: f32) -> Vector3 {
+        Vector3 { x, y, z }
+    }
+
+    fn object(id: u64, centroid: Vector3) -> Object {
+        let voxel = Voxel { x: 0, y: 0, z: 0 };
+        Object {
+            id,
+            grid_id: 0,
+            centroid,
+            min: voxel,
+            max: voxel,
+            voxel_count: 1,
+            energy: 1.0,
+            detected_at: Timestamp::UNIX_EPOCH,
+        }
+    }
+
+    #[test]
+    fn objects_outside_the_gate_are_not_associated() {
+        let tracks = [point(0.0, 0.0, 0.0)];
+        let objects = [point(3.5, 0.0, 0.0), point(0.0, 0.0, -4.0)];
+        assert!(associate(&tracks, &objects, 3.0).is_empty());
+        assert_eq!(associate(&tracks, &objects, 3.5), vec![(0, 0)]);
+    }
+
+    #[test]
+    fn closest_pairs_are_associated_first() {
+        // Object 0 is track 0's nearest, but it is nearer still to track 1, which takes it and
+        // leaves track 0 the further object 1.
+        let tracks = [point(0.0, 0.0, 0.0), point(1.1, 0.0, 0.0)];
+        let objects = [point(1.0, 0.0, 0.0), point(-1.5, 0.0, 0.0)];
+        assert_eq!(associate(&tracks, &objects, 3.0), vec![(1, 0), (0, 1)]);
+    }
+
+    #[test]
+    fn unmatched_objects_spawn_tracks() {
+        let config = TrackerConfig::default();
+        let tracks = [point(0.0, 0.0, 0.0)];
+        let objects = [
+            object(7, point(0.5, 0.0, 0.0)),
+            object(8, point(10.0, 0.0, 0.0)),
+        ];
+        let centroids: Vec<Vector3> = objects.iter().map(|object| object.centroid).collect();
+        let pairs = associate(&tracks, &centroids, config.gate_distance);
+        assert_eq!(pairs, vec![(0, 0)]);
+        assert_eq!(unclaimed(objects.len(), &pairs), vec![1]);
+
+        let track = Track::spawn(3, &objects[1], &config, Timestamp::UNIX_EPOCH);
+        assert_eq!(track.grid_id, 3);
+        assert_eq!(track.status, TrackStatus::Tentative);
+        assert_eq!(track.object_id, Some(8));
+        assert_eq!((track.hits, track.misses), (1, 0));
+        assert_eq!(track.position.distance(point(10.0, 0.0, 0.0)), 0.0);
+    }
+
+    #[test]
+    fn velocity_converges_on_a_constant_velocity_target() {
+        let config = TrackerConfig::default();
+        let velocity = point(1.5, -0.5, 0.25);
+        let start = point(2.0, 1.0, 0.0);
+        let mut track = Track::spawn(0, &object(0, start), &config, Timestamp::UNIX_EPOCH);
+        let dt = 0.1;
+        for step in 1..=100 {
+            let t = step as f32 * dt;
+            track.predict(dt, config.acceleration_noise);
+            track.correct(
+                map2(start, velocity, |p, v| p + v * t),
+                config.measurement_noise,
+            );
+        }
+        assert!(
+            track.velocity.distance(velocity) < 0.05,
+            "velocity {:?} after 100 passes",
+            (track.velocity.x, track.velocity.y, track.velocity.z)
+        );
+        assert!(
+            track
+                .position
+                .distance(map2(start, velocity, |p, v| p + v * 10.0))
+                < 0.05
+        );
+    }
+}
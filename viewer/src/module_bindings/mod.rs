@@ -6,23 +6,180 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+pub mod aggregation_mode_type;
+pub mod authorized_identity_table;
+pub mod authorized_identity_type;
+pub mod axis_covariance_type;
+pub mod camera_coverage_table;
+pub mod camera_coverage_type;
+pub mod camera_table;
+pub mod camera_type;
+pub mod camera_weight_type;
+pub mod clear_region_reducer;
+pub mod client_session_table;
+pub mod client_session_type;
+pub mod configure_aggregation_reducer;
+pub mod configure_consensus_reducer;
+pub mod configure_detection_reducer;
+pub mod configure_grid_reducer;
+pub mod configure_tracking_reducer;
+pub mod connectivity_type;
+pub mod consensus_config_type;
+pub mod cover_grid_reducer;
+pub mod create_grid_reducer;
+pub mod delete_grid_reducer;
+pub mod detection_config_type;
+pub mod detection_schedule_type;
+pub mod detection_table;
+pub mod detection_threshold_type;
+pub mod detection_type;
+pub mod grant_role_reducer;
+pub mod grid_maintenance_table;
+pub mod grid_maintenance_type;
 pub mod identity_connected_reducer;
 pub mod identity_disconnected_reducer;
+pub mod log_odds_config_type;
+pub mod maintenance_schedule_type;
+pub mod object_table;
+pub mod object_type;
+pub mod ping_reducer;
+pub mod quaternion_type;
+pub mod register_camera_reducer;
+pub mod reinitialize_reducer;
+pub mod report_clock_offset_reducer;
+pub mod reset_grid_reducer;
+pub mod revoke_role_reducer;
+pub mod role_type;
+pub mod run_detection_reducer;
+pub mod run_maintenance_reducer;
+pub mod set_camera_muted_reducer;
+pub mod set_camera_weight_reducer;
+pub mod set_client_version_reducer;
+pub mod set_frame_horizon_reducer;
+pub mod set_half_life_reducer;
+pub mod set_maintenance_interval_reducer;
+pub mod track_status_type;
+pub mod track_table;
+pub mod track_type;
+pub mod tracker_config_type;
+pub mod uncover_grid_reducer;
+pub mod update_camera_pose_reducer;
 pub mod update_voxel_reducer;
+pub mod update_voxels_reducer;
+pub mod vector3_type;
 pub mod voxel_grid_table;
 pub mod voxel_grid_type;
+pub mod voxel_hit_type;
 pub mod voxel_type;
-
+pub mod voxel_value_table;
+pub mod voxel_value_type;
+
+pub use aggregation_mode_type::AggregationMode;
+pub use authorized_identity_table::*;
+pub use authorized_identity_type::AuthorizedIdentity;
+pub use axis_covariance_type::AxisCovariance;
+pub use camera_coverage_table::*;
+pub use camera_coverage_type::CameraCoverage;
+pub use camera_table::*;
+pub use camera_type::Camera;
+pub use camera_weight_type::CameraWeight;
+pub use clear_region_reducer::{clear_region, set_flags_for_clear_region, ClearRegionCallbackId};
+pub use client_session_table::*;
+pub use client_session_type::ClientSession;
+pub use configure_aggregation_reducer::{
+    configure_aggregation, set_flags_for_configure_aggregation, ConfigureAggregationCallbackId,
+};
+pub use configure_consensus_reducer::{
+    configure_consensus, set_flags_for_configure_consensus, ConfigureConsensusCallbackId,
+};
+pub use configure_detection_reducer::{
+    configure_detection, set_flags_for_configure_detection, ConfigureDetectionCallbackId,
+};
+pub use configure_grid_reducer::{
+    configure_grid, set_flags_for_configure_grid, ConfigureGridCallbackId,
+};
+pub use configure_tracking_reducer::{
+    configure_tracking, set_flags_for_configure_tracking, ConfigureTrackingCallbackId,
+};
+pub use connectivity_type::Connectivity;
+pub use consensus_config_type::ConsensusConfig;
+pub use cover_grid_reducer::{cover_grid, set_flags_for_cover_grid, CoverGridCallbackId};
+pub use create_grid_reducer::{create_grid, set_flags_for_create_grid, CreateGridCallbackId};
+pub use delete_grid_reducer::{delete_grid, set_flags_for_delete_grid, DeleteGridCallbackId};
+pub use detection_config_type::DetectionConfig;
+pub use detection_schedule_type::DetectionSchedule;
+pub use detection_table::*;
+pub use detection_threshold_type::DetectionThreshold;
+pub use detection_type::Detection;
+pub use grant_role_reducer::{grant_role, set_flags_for_grant_role, GrantRoleCallbackId};
+pub use grid_maintenance_table::*;
+pub use grid_maintenance_type::GridMaintenance;
 pub use identity_connected_reducer::{
     identity_connected, set_flags_for_identity_connected, IdentityConnectedCallbackId,
 };
 pub use identity_disconnected_reducer::{
     identity_disconnected, set_flags_for_identity_disconnected, IdentityDisconnectedCallbackId,
 };
+pub use log_odds_config_type::LogOddsConfig;
+pub use maintenance_schedule_type::MaintenanceSchedule;
+pub use object_table::*;
+pub use object_type::Object;
+pub use ping_reducer::{ping, set_flags_for_ping, PingCallbackId};
+pub use quaternion_type::Quaternion;
+pub use register_camera_reducer::{
+    register_camera, set_flags_for_register_camera, RegisterCameraCallbackId,
+};
+pub use reinitialize_reducer::{reinitialize, set_flags_for_reinitialize, ReinitializeCallbackId};
+pub use report_clock_offset_reducer::{
+    report_clock_offset, set_flags_for_report_clock_offset, ReportClockOffsetCallbackId,
+};
+pub use reset_grid_reducer::{reset_grid, set_flags_for_reset_grid, ResetGridCallbackId};
+pub use revoke_role_reducer::{revoke_role, set_flags_for_revoke_role, RevokeRoleCallbackId};
+pub use role_type::Role;
+pub use run_detection_reducer::{
+    run_detection, set_flags_for_run_detection, RunDetectionCallbackId,
+};
+pub use run_maintenance_reducer::{
+    run_maintenance, set_flags_for_run_maintenance, RunMaintenanceCallbackId,
+};
+pub use set_camera_muted_reducer::{
+    set_camera_muted, set_flags_for_set_camera_muted, SetCameraMutedCallbackId,
+};
+pub use set_camera_weight_reducer::{
+    set_camera_weight, set_flags_for_set_camera_weight, SetCameraWeightCallbackId,
+};
+pub use set_client_version_reducer::{
+    set_client_version, set_flags_for_set_client_version, SetClientVersionCallbackId,
+};
+pub use set_frame_horizon_reducer::{
+    set_flags_for_set_frame_horizon, set_frame_horizon, SetFrameHorizonCallbackId,
+};
+pub use set_half_life_reducer::{
+    set_flags_for_set_half_life, set_half_life, SetHalfLifeCallbackId,
+};
+pub use set_maintenance_interval_reducer::{
+    set_flags_for_set_maintenance_interval, set_maintenance_interval,
+    SetMaintenanceIntervalCallbackId,
+};
+pub use track_status_type::TrackStatus;
+pub use track_table::*;
+pub use track_type::Track;
+pub use tracker_config_type::TrackerConfig;
+pub use uncover_grid_reducer::{set_flags_for_uncover_grid, uncover_grid, UncoverGridCallbackId};
+pub use update_camera_pose_reducer::{
+    set_flags_for_update_camera_pose, update_camera_pose, UpdateCameraPoseCallbackId,
+};
 pub use update_voxel_reducer::{set_flags_for_update_voxel, update_voxel, UpdateVoxelCallbackId};
+pub use update_voxels_reducer::{
+    set_flags_for_update_voxels, update_voxels, UpdateVoxelsCallbackId,
+};
+pub use vector3_type::Vector3;
 pub use voxel_grid_table::*;
 pub use voxel_grid_type::VoxelGrid;
+pub use voxel_hit_type::VoxelHit;
 pub use voxel_type::Voxel;
+pub use voxel_value_table::*;
+pub use voxel_value_type::VoxelValue;
 
 #[derive(Clone, PartialEq, Debug)]
 
@@ -32,9 +189,129 @@ pub use voxel_type::Voxel;
 /// to indicate which reducer caused the event.
 
 pub enum Reducer {
+    ClearRegion {
+        grid_id: u32,
+        min: Voxel,
+        max: Voxel,
+    },
+    ConfigureAggregation {
+        grid_id: u32,
+        mode: AggregationMode,
+    },
+    ConfigureConsensus {
+        grid_id: u32,
+        config: ConsensusConfig,
+    },
+    ConfigureDetection {
+        grid_id: u32,
+        config: DetectionConfig,
+    },
+    ConfigureGrid {
+        grid_id: u32,
+        nx: u32,
+        ny: u32,
+        nz: u32,
+        origin: Vector3,
+        voxel_size: f32,
+        orientation: Quaternion,
+    },
+    ConfigureTracking {
+        grid_id: u32,
+        config: TrackerConfig,
+    },
+    CoverGrid {
+        grid_id: u32,
+    },
+    CreateGrid {
+        name: String,
+        nx: u32,
+        ny: u32,
+        nz: u32,
+        origin: Vector3,
+        voxel_size: f32,
+        orientation: Quaternion,
+    },
+    DeleteGrid {
+        grid_id: u32,
+    },
+    GrantRole {
+        identity: __sdk::Identity,
+        role: Role,
+    },
     IdentityConnected,
     IdentityDisconnected,
-    UpdateVoxel { voxel: Voxel, value: f32 },
+    Ping {
+        client_time: __sdk::Timestamp,
+    },
+    RegisterCamera {
+        name: String,
+        width: u32,
+        height: u32,
+        fov: f32,
+        focal_length: f32,
+        position: Vector3,
+        orientation: Quaternion,
+    },
+    Reinitialize,
+    ReportClockOffset {
+        offset_micros: i64,
+        drift_ppm: f64,
+        round_trip_micros: i64,
+    },
+    ResetGrid {
+        grid_id: u32,
+    },
+    RevokeRole {
+        identity: __sdk::Identity,
+    },
+    RunDetection {
+        schedule: DetectionSchedule,
+    },
+    RunMaintenance {
+        schedule: MaintenanceSchedule,
+    },
+    SetCameraMuted {
+        identity: __sdk::Identity,
+        muted: bool,
+    },
+    SetCameraWeight {
+        identity: __sdk::Identity,
+        weight: CameraWeight,
+    },
+    SetClientVersion {
+        version: String,
+    },
+    SetFrameHorizon {
+        grid_id: u32,
+        horizon_ms: u64,
+    },
+    SetHalfLife {
+        grid_id: u32,
+        half_life: f32,
+    },
+    SetMaintenanceInterval {
+        grid_id: u32,
+        interval_ms: u64,
+    },
+    UncoverGrid {
+        grid_id: u32,
+    },
+    UpdateCameraPose {
+        position: Vector3,
+        orientation: Quaternion,
+    },
+    UpdateVoxel {
+        grid_id: u32,
+        voxel: Voxel,
+        value: f32,
+    },
+    UpdateVoxels {
+        grid_id: u32,
+        frame_id: u64,
+        captured_at: __sdk::Timestamp,
+        hits: Vec<VoxelHit>,
+        misses: Vec<Voxel>,
+    },
 }
 
 impl __sdk::InModule for Reducer {
@@ -44,9 +321,36 @@ impl __sdk::InModule for Reducer {
 impl __sdk::Reducer for Reducer {
     fn reducer_name(&self) -> &'static str {
         match self {
+            Reducer::ClearRegion { .. } => "clear_region",
+            Reducer::ConfigureAggregation { .. } => "configure_aggregation",
+            Reducer::ConfigureConsensus { .. } => "configure_consensus",
+            Reducer::ConfigureDetection { .. } => "configure_detection",
+            Reducer::ConfigureGrid { .. } => "configure_grid",
+            Reducer::ConfigureTracking { .. } => "configure_tracking",
+            Reducer::CoverGrid { .. } => "cover_grid",
+            Reducer::CreateGrid { .. } => "create_grid",
+            Reducer::DeleteGrid { .. } => "delete_grid",
+            Reducer::GrantRole { .. } => "grant_role",
             Reducer::IdentityConnected => "identity_connected",
             Reducer::IdentityDisconnected => "identity_disconnected",
+            Reducer::Ping { .. } => "ping",
+            Reducer::RegisterCamera { .. } => "register_camera",
+            Reducer::Reinitialize => "reinitialize",
+            Reducer::ReportClockOffset { .. } => "report_clock_offset",
+            Reducer::ResetGrid { .. } => "reset_grid",
+            Reducer::RevokeRole { .. } => "revoke_role",
+            Reducer::RunDetection { .. } => "run_detection",
+            Reducer::RunMaintenance { .. } => "run_maintenance",
+            Reducer::SetCameraMuted { .. } => "set_camera_muted",
+            Reducer::SetCameraWeight { .. } => "set_camera_weight",
+            Reducer::SetClientVersion { .. } => "set_client_version",
+            Reducer::SetFrameHorizon { .. } => "set_frame_horizon",
+            Reducer::SetHalfLife { .. } => "set_half_life",
+            Reducer::SetMaintenanceInterval { .. } => "set_maintenance_interval",
+            Reducer::UncoverGrid { .. } => "uncover_grid",
+            Reducer::UpdateCameraPose { .. } => "update_camera_pose",
             Reducer::UpdateVoxel { .. } => "update_voxel",
+            Reducer::UpdateVoxels { .. } => "update_voxels",
         }
     }
 }
@@ -54,6 +358,61 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
     type Error = __sdk::Error;
     fn try_from(value: __ws::ReducerCallInfo<__ws::BsatnFormat>) -> __sdk::Result<Self> {
         match &value.reducer_name[..] {
+            "clear_region" => Ok(
+                __sdk::parse_reducer_args::<clear_region_reducer::ClearRegionArgs>(
+                    "clear_region",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "configure_aggregation" => Ok(__sdk::parse_reducer_args::<
+                configure_aggregation_reducer::ConfigureAggregationArgs,
+            >("configure_aggregation", &value.args)?
+            .into()),
+            "configure_consensus" => Ok(__sdk::parse_reducer_args::<
+                configure_consensus_reducer::ConfigureConsensusArgs,
+            >("configure_consensus", &value.args)?
+            .into()),
+            "configure_detection" => Ok(__sdk::parse_reducer_args::<
+                configure_detection_reducer::ConfigureDetectionArgs,
+            >("configure_detection", &value.args)?
+            .into()),
+            "configure_grid" => Ok(__sdk::parse_reducer_args::<
+                configure_grid_reducer::ConfigureGridArgs,
+            >("configure_grid", &value.args)?
+            .into()),
+            "configure_tracking" => Ok(__sdk::parse_reducer_args::<
+                configure_tracking_reducer::ConfigureTrackingArgs,
+            >("configure_tracking", &value.args)?
+            .into()),
+            "cover_grid" => Ok(
+                __sdk::parse_reducer_args::<cover_grid_reducer::CoverGridArgs>(
+                    "cover_grid",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "create_grid" => Ok(
+                __sdk::parse_reducer_args::<create_grid_reducer::CreateGridArgs>(
+                    "create_grid",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "delete_grid" => Ok(
+                __sdk::parse_reducer_args::<delete_grid_reducer::DeleteGridArgs>(
+                    "delete_grid",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "grant_role" => Ok(
+                __sdk::parse_reducer_args::<grant_role_reducer::GrantRoleArgs>(
+                    "grant_role",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "identity_connected" => Ok(__sdk::parse_reducer_args::<
                 identity_connected_reducer::IdentityConnectedArgs,
             >("identity_connected", &value.args)?
@@ -62,6 +421,80 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 identity_disconnected_reducer::IdentityDisconnectedArgs,
             >("identity_disconnected", &value.args)?
             .into()),
+            "ping" => Ok(
+                __sdk::parse_reducer_args::<ping_reducer::PingArgs>("ping", &value.args)?.into(),
+            ),
+            "register_camera" => Ok(__sdk::parse_reducer_args::<
+                register_camera_reducer::RegisterCameraArgs,
+            >("register_camera", &value.args)?
+            .into()),
+            "reinitialize" => Ok(__sdk::parse_reducer_args::<
+                reinitialize_reducer::ReinitializeArgs,
+            >("reinitialize", &value.args)?
+            .into()),
+            "report_clock_offset" => Ok(__sdk::parse_reducer_args::<
+                report_clock_offset_reducer::ReportClockOffsetArgs,
+            >("report_clock_offset", &value.args)?
+            .into()),
+            "reset_grid" => Ok(
+                __sdk::parse_reducer_args::<reset_grid_reducer::ResetGridArgs>(
+                    "reset_grid",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "revoke_role" => Ok(
+                __sdk::parse_reducer_args::<revoke_role_reducer::RevokeRoleArgs>(
+                    "revoke_role",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "run_detection" => Ok(__sdk::parse_reducer_args::<
+                run_detection_reducer::RunDetectionArgs,
+            >("run_detection", &value.args)?
+            .into()),
+            "run_maintenance" => Ok(__sdk::parse_reducer_args::<
+                run_maintenance_reducer::RunMaintenanceArgs,
+            >("run_maintenance", &value.args)?
+            .into()),
+            "set_camera_muted" => Ok(__sdk::parse_reducer_args::<
+                set_camera_muted_reducer::SetCameraMutedArgs,
+            >("set_camera_muted", &value.args)?
+            .into()),
+            "set_camera_weight" => Ok(__sdk::parse_reducer_args::<
+                set_camera_weight_reducer::SetCameraWeightArgs,
+            >("set_camera_weight", &value.args)?
+            .into()),
+            "set_client_version" => Ok(__sdk::parse_reducer_args::<
+                set_client_version_reducer::SetClientVersionArgs,
+            >("set_client_version", &value.args)?
+            .into()),
+            "set_frame_horizon" => Ok(__sdk::parse_reducer_args::<
+                set_frame_horizon_reducer::SetFrameHorizonArgs,
+            >("set_frame_horizon", &value.args)?
+            .into()),
+            "set_half_life" => Ok(__sdk::parse_reducer_args::<
+                set_half_life_reducer::SetHalfLifeArgs,
+            >("set_half_life", &value.args)?
+            .into()),
+            "set_maintenance_interval" => {
+                Ok(__sdk::parse_reducer_args::<
+                    set_maintenance_interval_reducer::SetMaintenanceIntervalArgs,
+                >("set_maintenance_interval", &value.args)?
+                .into())
+            }
+            "uncover_grid" => Ok(
+                __sdk::parse_reducer_args::<uncover_grid_reducer::UncoverGridArgs>(
+                    "uncover_grid",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "update_camera_pose" => Ok(__sdk::parse_reducer_args::<
+                update_camera_pose_reducer::UpdateCameraPoseArgs,
+            >("update_camera_pose", &value.args)?
+            .into()),
             "update_voxel" => Ok(
                 __sdk::parse_reducer_args::<update_voxel_reducer::UpdateVoxelArgs>(
                     "update_voxel",
@@ -69,6 +502,10 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "update_voxels" => Ok(__sdk::parse_reducer_args::<
+                update_voxels_reducer::UpdateVoxelsArgs,
+            >("update_voxels", &value.args)?
+            .into()),
             unknown => {
                 Err(
                     __sdk::InternalError::unknown_name("reducer", unknown, "ReducerCallInfo")
@@ -83,7 +520,16 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct DbUpdate {
+    authorized_identity: __sdk::TableUpdate<AuthorizedIdentity>,
+    camera: __sdk::TableUpdate<Camera>,
+    camera_coverage: __sdk::TableUpdate<CameraCoverage>,
+    client_session: __sdk::TableUpdate<ClientSession>,
+    detection: __sdk::TableUpdate<Detection>,
+    grid_maintenance: __sdk::TableUpdate<GridMaintenance>,
+    object: __sdk::TableUpdate<Object>,
+    track: __sdk::TableUpdate<Track>,
     voxel_grid: __sdk::TableUpdate<VoxelGrid>,
+    voxel_value: __sdk::TableUpdate<VoxelValue>,
 }
 
 impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
@@ -92,9 +538,36 @@ impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
         let mut db_update = DbUpdate::default();
         for table_update in raw.tables {
             match &table_update.table_name[..] {
+                "authorized_identity" => db_update
+                    .authorized_identity
+                    .append(authorized_identity_table::parse_table_update(table_update)?),
+                "camera" => db_update
+                    .camera
+                    .append(camera_table::parse_table_update(table_update)?),
+                "camera_coverage" => db_update
+                    .camera_coverage
+                    .append(camera_coverage_table::parse_table_update(table_update)?),
+                "client_session" => db_update
+                    .client_session
+                    .append(client_session_table::parse_table_update(table_update)?),
+                "detection" => db_update
+                    .detection
+                    .append(detection_table::parse_table_update(table_update)?),
+                "grid_maintenance" => db_update
+                    .grid_maintenance
+                    .append(grid_maintenance_table::parse_table_update(table_update)?),
+                "object" => db_update
+                    .object
+                    .append(object_table::parse_table_update(table_update)?),
+                "track" => db_update
+                    .track
+                    .append(track_table::parse_table_update(table_update)?),
                 "voxel_grid" => db_update
                     .voxel_grid
                     .append(voxel_grid_table::parse_table_update(table_update)?),
+                "voxel_value" => db_update
+                    .voxel_value
+                    .append(voxel_value_table::parse_table_update(table_update)?),
 
                 unknown => {
                     return Err(__sdk::InternalError::unknown_name(
@@ -121,9 +594,39 @@ impl __sdk::DbUpdate for DbUpdate {
     ) -> AppliedDiff<'_> {
         let mut diff = AppliedDiff::default();
 
+        diff.authorized_identity = cache
+            .apply_diff_to_table::<AuthorizedIdentity>(
+                "authorized_identity",
+                &self.authorized_identity,
+            )
+            .with_updates_by_pk(|row| &row.identity);
+        diff.camera = cache
+            .apply_diff_to_table::<Camera>("camera", &self.camera)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.camera_coverage = cache
+            .apply_diff_to_table::<CameraCoverage>("camera_coverage", &self.camera_coverage)
+            .with_updates_by_pk(|row| &row.id);
+        diff.client_session = cache
+            .apply_diff_to_table::<ClientSession>("client_session", &self.client_session)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.detection = cache
+            .apply_diff_to_table::<Detection>("detection", &self.detection)
+            .with_updates_by_pk(|row| &row.id);
+        diff.grid_maintenance = cache
+            .apply_diff_to_table::<GridMaintenance>("grid_maintenance", &self.grid_maintenance)
+            .with_updates_by_pk(|row| &row.grid_id);
+        diff.object = cache
+            .apply_diff_to_table::<Object>("object", &self.object)
+            .with_updates_by_pk(|row| &row.id);
+        diff.track = cache
+            .apply_diff_to_table::<Track>("track", &self.track)
+            .with_updates_by_pk(|row| &row.id);
         diff.voxel_grid = cache
             .apply_diff_to_table::<VoxelGrid>("voxel_grid", &self.voxel_grid)
             .with_updates_by_pk(|row| &row.id);
+        diff.voxel_value = cache
+            .apply_diff_to_table::<VoxelValue>("voxel_value", &self.voxel_value)
+            .with_updates_by_pk(|row| &row.id);
 
         diff
     }
@@ -133,7 +636,16 @@ impl __sdk::DbUpdate for DbUpdate {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct AppliedDiff<'r> {
+    authorized_identity: __sdk::TableAppliedDiff<'r, AuthorizedIdentity>,
+    camera: __sdk::TableAppliedDiff<'r, Camera>,
+    camera_coverage: __sdk::TableAppliedDiff<'r, CameraCoverage>,
+    client_session: __sdk::TableAppliedDiff<'r, ClientSession>,
+    detection: __sdk::TableAppliedDiff<'r, Detection>,
+    grid_maintenance: __sdk::TableAppliedDiff<'r, GridMaintenance>,
+    object: __sdk::TableAppliedDiff<'r, Object>,
+    track: __sdk::TableAppliedDiff<'r, Track>,
     voxel_grid: __sdk::TableAppliedDiff<'r, VoxelGrid>,
+    voxel_value: __sdk::TableAppliedDiff<'r, VoxelValue>,
 }
 
 impl __sdk::InModule for AppliedDiff<'_> {
@@ -146,7 +658,32 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
         event: &EventContext,
         callbacks: &mut __sdk::DbCallbacks<RemoteModule>,
     ) {
+        callbacks.invoke_table_row_callbacks::<AuthorizedIdentity>(
+            "authorized_identity",
+            &self.authorized_identity,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Camera>("camera", &self.camera, event);
+        callbacks.invoke_table_row_callbacks::<CameraCoverage>(
+            "camera_coverage",
+            &self.camera_coverage,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ClientSession>(
+            "client_session",
+            &self.client_session,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Detection>("detection", &self.detection, event);
+        callbacks.invoke_table_row_callbacks::<GridMaintenance>(
+            "grid_maintenance",
+            &self.grid_maintenance,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Object>("object", &self.object, event);
+        callbacks.invoke_table_row_callbacks::<Track>("track", &self.track, event);
         callbacks.invoke_table_row_callbacks::<VoxelGrid>("voxel_grid", &self.voxel_grid, event);
+        callbacks.invoke_table_row_callbacks::<VoxelValue>("voxel_value", &self.voxel_value, event);
     }
 }
 
@@ -722,6 +1259,15 @@ impl __sdk::SpacetimeModule for RemoteModule {
     type SubscriptionHandle = SubscriptionHandle;
 
     fn register_tables(client_cache: &mut __sdk::ClientCache<Self>) {
+        authorized_identity_table::register_table(client_cache);
+        camera_table::register_table(client_cache);
+        camera_coverage_table::register_table(client_cache);
+        client_session_table::register_table(client_cache);
+        detection_table::register_table(client_cache);
+        grid_maintenance_table::register_table(client_cache);
+        object_table::register_table(client_cache);
+        track_table::register_table(client_cache);
         voxel_grid_table::register_table(client_cache);
+        voxel_value_table::register_table(client_cache);
     }
 }
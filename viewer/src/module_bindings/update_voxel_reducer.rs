@@ -11,6 +11,7 @@ use super::voxel_type::Voxel;
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
 pub(super) struct UpdateVoxelArgs {
+    pub grid_id: u32,
     pub voxel: Voxel,
     pub value: f32,
 }
@@ -18,6 +19,7 @@ pub(super) struct UpdateVoxelArgs {
 impl From<UpdateVoxelArgs> for super::Reducer {
     fn from(args: UpdateVoxelArgs) -> Self {
         Self::UpdateVoxel {
+            grid_id: args.grid_id,
             voxel: args.voxel,
             value: args.value,
         }
@@ -40,7 +42,7 @@ pub trait update_voxel {
     /// This method returns immediately, and errors only if we are unable to send the request.
     /// The reducer will run asynchronously in the future,
     ///  and its status can be observed by listening for [`Self::on_update_voxel`] callbacks.
-    fn update_voxel(&self, voxel: Voxel, value: f32) -> __sdk::Result<()>;
+    fn update_voxel(&self, grid_id: u32, voxel: Voxel, value: f32) -> __sdk::Result<()>;
     /// Register a callback to run whenever we are notified of an invocation of the reducer `update_voxel`.
     ///
     /// Callbacks should inspect the [`__sdk::ReducerEvent`] contained in the [`super::ReducerEventContext`]
@@ -50,7 +52,7 @@ pub trait update_voxel {
     /// to cancel the callback.
     fn on_update_voxel(
         &self,
-        callback: impl FnMut(&super::ReducerEventContext, &Voxel, &f32) + Send + 'static,
+        callback: impl FnMut(&super::ReducerEventContext, &u32, &Voxel, &f32) + Send + 'static,
     ) -> UpdateVoxelCallbackId;
     /// Cancel a callback previously registered by [`Self::on_update_voxel`],
     /// causing it not to run in the future.
@@ -58,13 +60,19 @@ pub trait update_voxel {
 }
 
 impl update_voxel for super::RemoteReducers {
-    fn update_voxel(&self, voxel: Voxel, value: f32) -> __sdk::Result<()> {
-        self.imp
-            .call_reducer("update_voxel", UpdateVoxelArgs { voxel, value })
+    fn update_voxel(&self, grid_id: u32, voxel: Voxel, value: f32) -> __sdk::Result<()> {
+        self.imp.call_reducer(
+            "update_voxel",
+            UpdateVoxelArgs {
+                grid_id,
+                voxel,
+                value,
+            },
+        )
     }
     fn on_update_voxel(
         &self,
-        mut callback: impl FnMut(&super::ReducerEventContext, &Voxel, &f32) + Send + 'static,
+        mut callback: impl FnMut(&super::ReducerEventContext, &u32, &Voxel, &f32) + Send + 'static,
     ) -> UpdateVoxelCallbackId {
         UpdateVoxelCallbackId(self.imp.on_reducer(
             "update_voxel",
@@ -72,7 +80,12 @@ impl update_voxel for super::RemoteReducers {
                 let super::ReducerEventContext {
                     event:
                         __sdk::ReducerEvent {
-                            reducer: super::Reducer::UpdateVoxel { voxel, value },
+                            reducer:
+                                super::Reducer::UpdateVoxel {
+                                    grid_id,
+                                    voxel,
+                                    value,
+                                },
                             ..
                         },
                     ..
@@ -80,7 +93,7 @@ impl update_voxel for super::RemoteReducers {
                 else {
                     unreachable!()
                 };
-                callback(ctx, voxel, value)
+                callback(ctx, grid_id, voxel, value)
             }),
         ))
     }
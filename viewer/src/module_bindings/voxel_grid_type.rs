@@ -6,12 +6,31 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+use super::aggregation_mode_type::AggregationMode;
+use super::consensus_config_type::ConsensusConfig;
+use super::detection_config_type::DetectionConfig;
+use super::quaternion_type::Quaternion;
+use super::tracker_config_type::TrackerConfig;
+use super::vector3_type::Vector3;
+
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
 pub struct VoxelGrid {
     pub id: u32,
+    pub name: String,
+    pub nx: u32,
+    pub ny: u32,
+    pub nz: u32,
+    pub origin: Vector3,
     pub voxel_size: f32,
-    pub grid: Vec<f32>,
+    pub orientation: Quaternion,
+    pub aggregation: AggregationMode,
+    pub half_life: f32,
+    pub maintenance_interval_ms: u64,
+    pub frame_horizon_ms: u64,
+    pub detection: DetectionConfig,
+    pub tracking: TrackerConfig,
+    pub consensus: ConsensusConfig,
 }
 
 impl __sdk::InModule for VoxelGrid {
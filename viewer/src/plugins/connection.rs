@@ -13,7 +13,13 @@ impl Plugin for ConnectionPlugin {
                 .with_uri(HOST)
                 .with_module_name(DB_NAME)
                 .with_run_fn(DbConnection::run_threaded)
-                .add_table(RemoteTables::voxel_grid),
+                .add_table(RemoteTables::voxel_grid)
+                .add_table(RemoteTables::voxel_value)
+                .add_table(RemoteTables::detection)
+                .add_table(RemoteTables::object)
+                .add_table(RemoteTables::track)
+                .add_table(RemoteTables::camera)
+                .add_table(RemoteTables::client_session),
         );
     }
 }
@@ -24,10 +30,8 @@ fn on_connected(mut events: ReadStdbConnectedEvent, stdb: Res<StdbConnection<DbC
     }
 }
 
-fn on_voxel_grid_inserted(mut commands: Commands, mut events: ReadInsertEvent<VoxelGrid>) {
+fn on_voxel_value_inserted(mut commands: Commands, mut events: ReadInsertEvent<VoxelValue>) {
     for event in events.read() {
-        for value in event.row.grid {
-            if value > std::f32::EPSILON {}
-        }
+        if event.row.value > std::f32::EPSILON {}
     }
 }